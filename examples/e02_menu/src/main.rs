@@ -3,54 +3,24 @@
 //! You are expected to be familier with serenity's basics.
 
 use std::env;
-use std::sync::Arc;
 
 use serenity::async_trait;
 use serenity::builder::CreateMessage;
 use serenity::client::{Client, Context, EventHandler};
 use serenity::framework::standard::macros::{command, group};
 use serenity::framework::standard::{CommandResult, StandardFramework};
-use serenity::model::prelude::{Message, Reaction, ReactionType, Ready};
+use serenity::model::prelude::{Message, Ready};
 use serenity::prelude::GatewayIntents;
 // Bring menu items into scope along.
 use serenity_utils::menu::*;
 
-// A custom function to be used as a control function for the menu.
-async fn first_page<'a>(menu: &mut Menu<'a>, reaction: Reaction) {
-    // Remove the reaction used to change the menu.
-    let _ = &reaction.delete(&menu.ctx.http).await;
-
-    // Set page number to `0`.
-    menu.options.page = 0;
-}
-
-// A custom function to be used as a control function for the menu.
-async fn last_page<'a>(menu: &mut Menu<'a>, reaction: Reaction) {
-    // Remove the reaction used to change the menu.
-    let _ = &reaction.delete(&menu.ctx.http).await;
-
-    // Set page number to total - 1.
-    menu.options.page = menu.pages.len() - 1;
-}
-
 #[command]
 async fn scoreboard(ctx: &Context, msg: &Message) -> CommandResult {
     // We'll use a reaction-based menu to display the scoreboard.
 
-    // First, let's create controls for the menu.
-    let controls = vec![
-        Control::new(ReactionType::from('⏪'), Arc::new(|m, r| Box::pin(first_page(m, r)))),
-        Control::new(ReactionType::from('◀'), Arc::new(|m, r| Box::pin(prev_page(m, r)))),
-        Control::new(ReactionType::from('❌'), Arc::new(|m, r| Box::pin(close_menu(m, r)))),
-        Control::new(ReactionType::from('▶'), Arc::new(|m, r| Box::pin(next_page(m, r)))),
-        Control::new(ReactionType::from('⏩'), Arc::new(|m, r| Box::pin(last_page(m, r)))),
-    ];
-
-    // Let's create options for the menu.
-    let options = MenuOptions {
-        controls,
-        ..Default::default()
-    };
+    // First, let's create options for the menu. These options have controls
+    // to jump to the first and last pages along with the default controls.
+    let options = MenuOptions::default_controls_with_ends();
 
     // Now, we need pages to display the scoreboard.
     let mut page_one = CreateMessage::default();
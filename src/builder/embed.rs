@@ -30,11 +30,41 @@
 //!
 //! [`HashMap`]: std::collections::HashMap
 
+use std::fmt::{Display, Write};
+
 use serenity::builder::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter};
-use serenity::model::channel::EmbedField;
+use serenity::json::{json, Value};
+use serenity::model::channel::{Embed, EmbedField, EmbedProvider};
 use serenity::model::Timestamp;
 use serenity::utils::Colour;
 
+use crate::error::Error;
+
+/// The maximum length of an embed's title.
+const TITLE_LIMIT: usize = 256;
+/// The maximum length of an embed's description.
+const DESCRIPTION_LIMIT: usize = 4096;
+/// The maximum number of fields an embed can have.
+const FIELD_COUNT_LIMIT: usize = 25;
+/// The maximum length of a field's name.
+const FIELD_NAME_LIMIT: usize = 256;
+/// The maximum length of a field's value.
+const FIELD_VALUE_LIMIT: usize = 1024;
+/// The maximum length of an embed's footer text.
+const FOOTER_LIMIT: usize = 2048;
+/// The maximum length of an embed's author name.
+const AUTHOR_LIMIT: usize = 256;
+/// The maximum combined length of an embed's text.
+const TOTAL_LIMIT: usize = 6000;
+
+/// Returns the length of `text` as counted by Discord, in UTF-16 code units.
+///
+/// Many emojis take two code units, so this can be more than the number of
+/// characters.
+fn discord_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
 /// A struct to build the author portion of an embed.
 ///
 /// It is meant to serve as an alternative to serenity's [`CreateEmbedAuthor`].
@@ -333,6 +363,74 @@ impl From<&EmbedFieldBuilder> for EmbedField {
     }
 }
 
+/// A struct to build the provider portion of an embed.
+///
+/// All fields have setter methods like serenity's builders to allow you to pass
+/// in a wide range of parameters/arguments.
+///
+/// Both fields are optional.
+///
+/// **Note:** Discord ignores the provider of embeds sent by bots. It is only
+/// present on embeds generated by Discord, such as link previews.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity_utils::builder::embed::EmbedProviderBuilder;
+/// #
+/// let mut provider = EmbedProviderBuilder::new();
+///
+/// // Fields can set using the setter.
+/// provider.set_name("name");
+///
+/// // Or by directly mutating the struct.
+/// provider.url = Some("url".to_string());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EmbedProviderBuilder {
+    /// The name of the provider.
+    pub name: Option<String>,
+    /// The URL of the provider.
+    pub url: Option<String>,
+}
+
+impl EmbedProviderBuilder {
+    /// Creates an empty [`EmbedProviderBuilder`] object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the provider's name.
+    pub fn set_name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Sets the provider's URL.
+    pub fn set_url<S: ToString>(&mut self, url: S) -> &mut Self {
+        self.url = Some(url.to_string());
+
+        self
+    }
+
+    fn to_value(&self) -> Value {
+        json!({
+            "name": self.name,
+            "url": self.url,
+        })
+    }
+}
+
+impl From<&EmbedProvider> for EmbedProviderBuilder {
+    fn from(provider: &EmbedProvider) -> Self {
+        Self {
+            name: provider.name.clone(),
+            url: provider.url.clone(),
+        }
+    }
+}
+
 /// A struct to build an embed.
 ///
 /// It is meant to serve as an alternative to serenity's [`CreateEmbed`].
@@ -358,6 +456,13 @@ impl From<&EmbedFieldBuilder> for EmbedField {
 /// embed.title = Some("title".to_string());
 /// ```
 ///
+/// ## Fields honored by Discord
+///
+/// Discord ignores the `provider` and `video` fields of embeds sent by bots.
+/// They are only present so embeds received from Discord, such as link
+/// previews, can be represented without losing information. All other fields
+/// are honored.
+///
 /// [`HashMap`]: std::collections::HashMap
 #[derive(Clone, Debug, Default)]
 pub struct EmbedBuilder {
@@ -367,7 +472,7 @@ pub struct EmbedBuilder {
     pub colour: Option<Colour>,
     /// The description of the embed.
     ///
-    /// It can't be longer than 2048 characters.
+    /// It can't be longer than 4096 characters.
     pub description: Option<String>,
     /// The fields of the embed.
     pub fields: Vec<EmbedFieldBuilder>,
@@ -377,6 +482,10 @@ pub struct EmbedBuilder {
     ///
     /// This only supports HTTP(S).
     pub image: Option<String>,
+    /// The provider of the embed.
+    ///
+    /// Discord ignores this for embeds sent by bots.
+    pub provider: Option<EmbedProviderBuilder>,
     /// The thumbnail of the embed.
     ///
     /// This only supports HTTP(S).
@@ -387,6 +496,10 @@ pub struct EmbedBuilder {
     pub title: Option<String>,
     /// The title url of the embed.
     pub url: Option<String>,
+    /// The video URL of the embed.
+    ///
+    /// Discord ignores this for embeds sent by bots.
+    pub video: Option<String>,
     /// The attachment of the embed.
     pub attachment: Option<String>,
 }
@@ -406,7 +519,11 @@ impl EmbedBuilder {
     /// `ChannelId::send_files`) with the provided filename or else this won't
     /// work.
     ///
+    /// The `attachment` field overrides the `image` field. Prefer
+    /// [`set_image_attachment`], which sets the `image` field directly.
+    ///
     /// [`set_image`]: EmbedBuilder::set_image()
+    /// [`set_image_attachment`]: EmbedBuilder::set_image_attachment()
     pub fn set_attachment<S: ToString>(&mut self, filename: S) -> &mut Self {
         let mut filename = filename.to_string();
         filename.insert_str(0, "attachment://");
@@ -461,13 +578,50 @@ impl EmbedBuilder {
 
     /// Sets the embed's description.
     ///
-    /// It can't be longer than 2048 characters.
+    /// It can't be longer than 4096 characters.
     pub fn set_description<S: ToString>(&mut self, description: S) -> &mut Self {
         self.description = Some(description.to_string());
 
         self
     }
 
+    /// Appends text to the embed's description.
+    ///
+    /// The description is created if the embed doesn't have one. The
+    /// description can't be longer than 4096 characters. If the `tracing`
+    /// feature is enabled, a warning is emitted when it becomes longer.
+    ///
+    /// ```
+    /// # use serenity_utils::builder::embed::EmbedBuilder;
+    /// #
+    /// let mut embed = EmbedBuilder::new();
+    /// for n in 1..=3 {
+    ///     embed.push_description_line(format!("Line {}", n));
+    /// }
+    ///
+    /// assert_eq!(embed.description.as_deref(), Some("Line 1\nLine 2\nLine 3\n"));
+    /// ```
+    pub fn push_description<D: Display>(&mut self, text: D) -> &mut Self {
+        let description = self.description.get_or_insert_with(String::new);
+        let _ = write!(description, "{}", text);
+
+        #[cfg(feature = "tracing")]
+        if discord_len(description) > DESCRIPTION_LIMIT {
+            tracing::warn!(limit = DESCRIPTION_LIMIT, "embed description is too long");
+        }
+
+        self
+    }
+
+    /// Appends text followed by a newline to the embed's description.
+    ///
+    /// See [`push_description`] for more details.
+    ///
+    /// [`push_description`]: EmbedBuilder::push_description()
+    pub fn push_description_line<D: Display>(&mut self, text: D) -> &mut Self {
+        self.push_description(text).push_description('\n')
+    }
+
     /// Adds a field to the embed.
     ///
     /// The name of a field can contain 256 characters at most. The value can
@@ -500,6 +654,31 @@ impl EmbedBuilder {
         self
     }
 
+    /// Adds multiple field builders to the embed.
+    ///
+    /// This is like [`add_fields`], but takes [`EmbedFieldBuilder`]s instead
+    /// of tuples.
+    ///
+    /// [`add_fields`]: EmbedBuilder::add_fields
+    pub fn add_field_builders<I>(&mut self, fields: I) -> &mut Self
+    where
+        I: IntoIterator<Item = EmbedFieldBuilder>,
+    {
+        self.fields.extend(fields);
+
+        self
+    }
+
+    /// Replaces the embed's fields with `fields`.
+    pub fn set_field_builders<I>(&mut self, fields: I) -> &mut Self
+    where
+        I: IntoIterator<Item = EmbedFieldBuilder>,
+    {
+        self.fields = fields.into_iter().collect();
+
+        self
+    }
+
     /// Sets field at position `index`, if it is within bounds.
     pub fn set_field_at(&mut self, index: usize, field: EmbedFieldBuilder) -> &mut Self {
         if self.fields.len() - 1 > index {
@@ -551,6 +730,26 @@ impl EmbedBuilder {
         self
     }
 
+    /// Sets the embed's image to an attached file.
+    ///
+    /// This sets the image URL to "attachment://filename". The file must still
+    /// be attached to the message with the same filename, for example with
+    /// [`MessageBuilder::add_file`], or the image won't be displayed.
+    ///
+    /// [`MessageBuilder::add_file`]: crate::builder::message::MessageBuilder::add_file()
+    pub fn set_image_attachment<S: ToString>(&mut self, filename: S) -> &mut Self {
+        self.set_image(format!("attachment://{}", filename.to_string()))
+    }
+
+    /// Sets the embed's provider.
+    ///
+    /// Discord ignores the provider of embeds sent by bots.
+    pub fn set_provider(&mut self, provider: EmbedProviderBuilder) -> &mut Self {
+        self.provider = Some(provider);
+
+        self
+    }
+
     /// Sets the embed's thumbnail. This only supports HTTP(S).
     pub fn set_thumbnail<S: ToString>(&mut self, url: S) -> &mut Self {
         self.thumbnail = Some(url.to_string());
@@ -558,6 +757,17 @@ impl EmbedBuilder {
         self
     }
 
+    /// Sets the embed's thumbnail to an attached file.
+    ///
+    /// This sets the thumbnail URL to "attachment://filename". The file must
+    /// still be attached to the message with the same filename, for example
+    /// with [`MessageBuilder::add_file`], or the thumbnail won't be displayed.
+    ///
+    /// [`MessageBuilder::add_file`]: crate::builder::message::MessageBuilder::add_file()
+    pub fn set_thumbnail_attachment<S: ToString>(&mut self, filename: S) -> &mut Self {
+        self.set_thumbnail(format!("attachment://{}", filename.to_string()))
+    }
+
     /// Sets the embed's timestamp.
     pub fn set_timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
         self.timestamp = Some(timestamp.into());
@@ -579,6 +789,130 @@ impl EmbedBuilder {
         self
     }
 
+    /// Sets the embed's video URL.
+    ///
+    /// Discord ignores the video of embeds sent by bots.
+    pub fn set_video<S: ToString>(&mut self, url: S) -> &mut Self {
+        self.video = Some(url.to_string());
+
+        self
+    }
+
+    /// Removes the embed's author.
+    pub fn clear_author(&mut self) -> &mut Self {
+        self.author = None;
+
+        self
+    }
+
+    /// Removes all fields of the embed.
+    ///
+    /// The allocated space of the `fields` vector is kept so it can be reused.
+    pub fn clear_fields(&mut self) -> &mut Self {
+        self.fields.clear();
+
+        self
+    }
+
+    /// Removes the embed's footer.
+    pub fn clear_footer(&mut self) -> &mut Self {
+        self.footer = None;
+
+        self
+    }
+
+    /// Resets the embed to an empty embed.
+    ///
+    /// This is the same as creating a new builder with [`EmbedBuilder::new`],
+    /// except that the allocated space of the `fields` vector is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut fields = std::mem::take(&mut self.fields);
+        fields.clear();
+
+        *self = Self {
+            fields,
+            ..Default::default()
+        };
+
+        self
+    }
+
+    /// Returns the combined length of the embed's text.
+    ///
+    /// This is the length of the title, description, field names and values,
+    /// footer text and author name. Lengths are counted in UTF-16 code units,
+    /// like Discord does, so many emojis count as two.
+    pub fn total_len(&self) -> usize {
+        let fields = self.fields.iter().map(|f| discord_len(&f.name) + discord_len(&f.value));
+
+        self.title.as_deref().map_or(0, discord_len)
+            + self.description.as_deref().map_or(0, discord_len)
+            + fields.sum::<usize>()
+            + self.footer.as_ref().map_or(0, |f| discord_len(&f.text))
+            + self.author.as_ref().map_or(0, |a| discord_len(&a.name))
+    }
+
+    /// Checks that the embed is within Discord's limits.
+    ///
+    /// Lengths are counted in UTF-16 code units, like Discord does, so an embed
+    /// that is within the limits in characters can still be too long.
+    ///
+    /// ```
+    /// # use serenity_utils::builder::embed::EmbedBuilder;
+    /// #
+    /// let mut embed = EmbedBuilder::new();
+    /// embed.set_title("title");
+    /// assert!(embed.validate().is_ok());
+    ///
+    /// embed.set_title("a".repeat(257));
+    /// assert!(embed.validate().is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Other`] describing the first limit that is exceeded.
+    ///
+    /// [`Error::Other`]: crate::error::Error::Other
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> Result<(), Error> {
+        let check = |text: &str, limit: usize, name: &str| {
+            if discord_len(text) > limit {
+                Err(Error::msg(format!("The embed's {} is longer than {}.", name, limit)))
+            } else {
+                Ok(())
+            }
+        };
+
+        check(self.title.as_deref().unwrap_or_default(), TITLE_LIMIT, "title")?;
+        check(self.description.as_deref().unwrap_or_default(), DESCRIPTION_LIMIT, "description")?;
+
+        if self.fields.len() > FIELD_COUNT_LIMIT {
+            return Err(Error::msg(format!(
+                "The embed has more than {} fields.",
+                FIELD_COUNT_LIMIT
+            )));
+        }
+
+        for field in &self.fields {
+            check(&field.name, FIELD_NAME_LIMIT, "field name")?;
+            check(&field.value, FIELD_VALUE_LIMIT, "field value")?;
+        }
+
+        if let Some(footer) = &self.footer {
+            check(&footer.text, FOOTER_LIMIT, "footer")?;
+        }
+
+        if let Some(author) = &self.author {
+            check(&author.name, AUTHOR_LIMIT, "author name")?;
+        }
+
+        if self.total_len() > TOTAL_LIMIT {
+            return Err(Error::msg(format!("The embed is longer than {} in total.", TOTAL_LIMIT)));
+        }
+
+        Ok(())
+    }
+
     /// Converts [`EmbedBuilder`] into serenity's [`CreateEmbed`].
     pub fn to_create_embed(&self) -> CreateEmbed {
         self.into()
@@ -621,6 +955,10 @@ impl From<EmbedBuilder> for CreateEmbed {
             embed.image(image);
         }
 
+        if let Some(provider) = embed_builder.provider {
+            embed.0.insert("provider", provider.to_value());
+        }
+
         if let Some(thumbnail) = embed_builder.thumbnail {
             embed.thumbnail(thumbnail);
         }
@@ -637,8 +975,13 @@ impl From<EmbedBuilder> for CreateEmbed {
             embed.url(url);
         }
 
+        if let Some(video) = embed_builder.video {
+            embed.0.insert("video", json!({ "url": video }));
+        }
+
+        // The `attachment://` prefix is added by `set_attachment`.
         if let Some(attachment) = embed_builder.attachment {
-            embed.attachment(attachment);
+            embed.image(attachment);
         }
 
         embed
@@ -681,6 +1024,10 @@ impl From<&EmbedBuilder> for CreateEmbed {
             embed.image(image);
         }
 
+        if let Some(provider) = &embed_builder.provider {
+            embed.0.insert("provider", provider.to_value());
+        }
+
         if let Some(thumbnail) = &embed_builder.thumbnail {
             embed.thumbnail(thumbnail);
         }
@@ -697,10 +1044,52 @@ impl From<&EmbedBuilder> for CreateEmbed {
             embed.url(url);
         }
 
+        if let Some(video) = &embed_builder.video {
+            embed.0.insert("video", json!({ "url": video }));
+        }
+
+        // The `attachment://` prefix is added by `set_attachment`.
         if let Some(attachment) = &embed_builder.attachment {
-            embed.attachment(attachment);
+            embed.image(attachment);
         }
 
         embed
     }
 }
+
+impl From<&Embed> for EmbedBuilder {
+    fn from(embed: &Embed) -> Self {
+        Self {
+            author: embed.author.as_ref().map(|a| EmbedAuthorBuilder {
+                icon_url: a.icon_url.clone(),
+                name: a.name.clone(),
+                url: a.url.clone(),
+            }),
+            colour: embed.colour,
+            description: embed.description.clone(),
+            fields: embed
+                .fields
+                .iter()
+                .map(|f| EmbedFieldBuilder::new(&f.name, &f.value, f.inline))
+                .collect(),
+            footer: embed.footer.as_ref().map(|f| EmbedFooterBuilder {
+                icon_url: f.icon_url.clone(),
+                text: f.text.clone(),
+            }),
+            image: embed.image.as_ref().map(|i| i.url.clone()),
+            provider: embed.provider.as_ref().map(EmbedProviderBuilder::from),
+            thumbnail: embed.thumbnail.as_ref().map(|t| t.url.clone()),
+            timestamp: embed.timestamp.as_deref().and_then(|t| Timestamp::parse(t).ok()),
+            title: embed.title.clone(),
+            url: embed.url.clone(),
+            video: embed.video.as_ref().map(|v| v.url.clone()),
+            attachment: None,
+        }
+    }
+}
+
+impl From<Embed> for EmbedBuilder {
+    fn from(embed: Embed) -> Self {
+        Self::from(&embed)
+    }
+}
@@ -31,9 +31,14 @@
 //! [`HashMap`]: std::collections::HashMap
 
 use serenity::builder::{CreateMessage, EditMessage};
-use serenity::model::channel::{AttachmentType, ReactionType};
+use serenity::model::channel::{AttachmentType, Message, ReactionType};
+use serenity::utils::Colour;
 
 use super::embed::EmbedBuilder;
+use crate::formatting::{pagify, text_to_file, PagifyOptions};
+
+/// The maximum number of characters a message's content can have.
+const CONTENT_LIMIT: usize = 2000;
 
 /// A struct to build a message.
 ///
@@ -86,6 +91,23 @@ impl<'a> MessageBuilder<'a> {
         Self::default()
     }
 
+    /// Creates a [`MessageBuilder`] object from an existing message.
+    ///
+    /// The content, the first embed and the text-to-speech indicator are
+    /// copied, so the message can be modified and sent again. An empty content
+    /// is treated as no content.
+    ///
+    /// Files and reactions are not copied. Attachments can't be re-uploaded
+    /// from their URLs, so they must be added again if needed.
+    pub fn from_message(message: &Message) -> Self {
+        Self {
+            content: (!message.content.is_empty()).then(|| message.content.clone()),
+            embed: message.embeds.first().map(EmbedBuilder::from),
+            tts: message.tts,
+            ..Default::default()
+        }
+    }
+
     /// Sets the message's content.
     pub fn set_content<S: ToString>(&mut self, content: S) -> &mut Self {
         self.content = Some(content.to_string());
@@ -133,6 +155,41 @@ impl<'a> MessageBuilder<'a> {
         self.set_embed(embed)
     }
 
+    /// Sets the message's content if it fits in a message, otherwise attaches
+    /// it as a text file.
+    ///
+    /// If `text` is longer than 2000 characters, the content is cleared and
+    /// `text` is added to the message's files as `file.txt`.
+    ///
+    /// See [`into_messages`] to split long content into multiple messages
+    /// instead.
+    ///
+    /// [`into_messages`]: MessageBuilder::into_messages()
+    pub fn set_content_or_file(&mut self, text: String) -> &mut Self {
+        if text.chars().count() <= CONTENT_LIMIT {
+            self.content = Some(text);
+        } else {
+            self.content = None;
+            self.files.push(text_to_file(text, None::<&str>, false));
+        }
+
+        self
+    }
+
+    /// Sets the colour of the message's embed if it doesn't have one.
+    ///
+    /// This only affects the embed currently set. If the message has no
+    /// embed or its embed already has a colour, nothing is changed.
+    pub fn set_default_embed_colour<C: Into<Colour>>(&mut self, colour: C) -> &mut Self {
+        if let Some(embed) = &mut self.embed {
+            if embed.colour.is_none() {
+                embed.colour = Some(colour.into());
+            }
+        }
+
+        self
+    }
+
     /// Adds a file to include in the message.
     ///
     /// It does not overwrite previously set files.
@@ -218,8 +275,61 @@ impl<'a> MessageBuilder<'a> {
         self
     }
 
+    /// Splits the message into multiple messages if its content is too long.
+    ///
+    /// The content is broken into pages of at most 2000 characters using
+    /// [`pagify`], and each page is put in a separate [`MessageBuilder`]. Mass
+    /// mentions in the content are not escaped. The embed, files and reactions
+    /// are kept on the last message, and every message keeps the `tts` setting.
+    ///
+    /// If the content fits in a single message, a vector containing only this
+    /// message is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use serenity_utils::builder::message::MessageBuilder;
+    /// #
+    /// let mut message = MessageBuilder::new();
+    /// message.set_content("a ".repeat(1500));
+    ///
+    /// assert_eq!(message.into_messages().len(), 2);
+    /// ```
+    ///
+    /// [`pagify`]: crate::formatting::pagify
+    pub fn into_messages(mut self) -> Vec<Self> {
+        let content = match self.content.take() {
+            Some(c) if c.chars().count() > CONTENT_LIMIT => c,
+            content => {
+                self.content = content;
+                return vec![self];
+            },
+        };
+
+        let mut options = PagifyOptions::new();
+        options.escape_mass_mentions(false).shorten_by(0).page_length(CONTENT_LIMIT);
+
+        let mut pages = pagify(content, options);
+        let last = pages.pop();
+
+        let mut messages = pages
+            .into_iter()
+            .map(|page| {
+                let mut message = Self::new();
+                message.set_content(page).set_tts(self.tts);
+
+                message
+            })
+            .collect::<Vec<_>>();
+
+        self.content = last;
+        messages.push(self);
+
+        messages
+    }
+
     /// Converts [`MessageBuilder`] into serenity's [`CreateMessage`].
-    pub fn to_create_message(&self) -> CreateMessage {
+    pub fn to_create_message(&self) -> CreateMessage<'_> {
         self.into()
     }
 
@@ -227,7 +337,7 @@ impl<'a> MessageBuilder<'a> {
     ///
     /// The resultant [`EditMessage`] only has content and embed — all other
     /// fields are ignored.
-    pub fn to_edit_message(&self) -> EditMessage {
+    pub fn to_edit_message(&self) -> EditMessage<'_> {
         self.into()
     }
 }
@@ -1,11 +1,29 @@
 //! Provides a trait to convert strings into serenity's guild-specific models.
 //!
-//! The trait provides two methods:
+//! The trait is implemented for [`Role`], [`Member`], [`User`],
+//! [`GuildChannel`], [`Emoji`], [`Webhook`] and [`Colour`]. Colours don't
+//! depend on the guild, see [`parse_colour`].
+//!
+//! The [`ActionableMember`] trait resolves only members that can be moderated
+//! by the invoker and the current user/bot, and the [`ChannelConversion`]
+//! trait resolves only channels of a given type, like text channels.
+//!
+//! The trait provides seven methods:
 //! - [`from_guild_and_str`]
 //! - [`from_guild_id_and_str`]
+//! - [`from_guild_id_and_str_with`]
+//! - [`from_guild_id_and_str_fuzzy`]
+//! - [`all_from_guild_id_and_str`]
+//! - [`from_message_and_str`]
+//! - [`try_from_guild_id_and_str`]
 //!
 //! The first method is available only when `cache` feature is enabled. The
-//! second method is always available.
+//! other methods are always available. The third method matches names as set
+//! in [`ConversionOptions`], for example ignoring case, the fourth method
+//! matches partial names and the fifth method returns every match instead of
+//! the first one. The sixth method also resolves the [`ME_KEYWORD`] and
+//! [`HERE_KEYWORD`] keywords, and the last method returns an error if a
+//! request fails instead of treating it as not found.
 //!
 //! ## Limitation
 //!
@@ -59,12 +77,25 @@
 //!
 //! [`from_guild_and_str`]: Conversion::from_guild_and_str
 //! [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+//! [`from_guild_id_and_str_with`]: Conversion::from_guild_id_and_str_with
+//! [`from_guild_id_and_str_fuzzy`]: Conversion::from_guild_id_and_str_fuzzy
+//! [`all_from_guild_id_and_str`]: Conversion::all_from_guild_id_and_str
+//! [`from_message_and_str`]: Conversion::from_message_and_str
+//! [`try_from_guild_id_and_str`]: Conversion::try_from_guild_id_and_str
+//! [`parse_colour`]: crate::misc::parse_colour
 
 use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
 
+use serenity::http::{HttpError, StatusCode};
 use serenity::model::prelude::*;
 use serenity::prelude::Context;
-use serenity::{async_trait, utils};
+use serenity::utils::Colour;
+use serenity::{async_trait, utils, Error as SerenityError};
+
+use crate::misc::parse_colour;
+use crate::Error;
 
 /// A trait to convert a string into serenity's models.
 ///
@@ -119,8 +150,246 @@ pub trait Conversion {
     ) -> Option<Self::Item>
     where
         Self: Sized;
+
+    /// Converts `arg` into the specified type, matching names as set in
+    /// `options`.
+    ///
+    /// This is like [`from_guild_id_and_str`], which matches names exactly.
+    /// See [`ConversionOptions`] for the available settings.
+    ///
+    /// The default implementation ignores `options`.
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let _ = options;
+
+        Self::from_guild_id_and_str(ctx, guild_id, arg).await
+    }
+
+    /// Converts `arg` into the specified type, matching names loosely.
+    ///
+    /// This is like [`from_guild_id_and_str`], but if `arg` isn't an ID or a
+    /// mention, names are compared ignoring case and don't have to be
+    /// complete. The closest match is returned: an exact name first, then a
+    /// name starting with `arg`, then a name containing `arg`. Ties are broken
+    /// by the lowest ID, so the result doesn't change between calls.
+    ///
+    /// The default implementation is the same as [`from_guild_id_and_str`].
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        Self::from_guild_id_and_str(ctx, guild_id, arg).await
+    }
+
+    /// Converts `arg` into every matching item, so the caller can choose
+    /// between them.
+    ///
+    /// This is like [`from_guild_id_and_str`], but doesn't stop at the first
+    /// match. Several members can share a display name, for example. Names
+    /// are matched exactly and the items are sorted by ID. For an ID or a
+    /// mention, at most one item is returned.
+    ///
+    /// The default implementation returns the item returned by
+    /// [`from_guild_id_and_str`], if any.
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        Self::from_guild_id_and_str(ctx, guild_id, arg).await.into_iter().collect()
+    }
+
+    /// Converts `arg` into the specified type, using the message's guild.
+    ///
+    /// Unlike the other methods, this knows who invoked the command and where,
+    /// so some types resolve a keyword to the invoker's context:
+    /// - [`Member`] resolves [`ME_KEYWORD`] to the message's author.
+    /// - [`GuildChannel`] resolves [`HERE_KEYWORD`] to the message's channel.
+    ///
+    /// Keywords are matched case-insensitively and take precedence over names.
+    /// Other arguments are resolved with [`from_guild_id_and_str`].
+    ///
+    /// `None` is returned if the message wasn't sent in a guild.
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    async fn from_message_and_str(ctx: &Context, msg: &Message, arg: &str) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        Self::from_guild_id_and_str(ctx, msg.guild_id?, arg).await
+    }
+
+    /// Converts `arg` into the specified type, reporting failed requests.
+    ///
+    /// This is like [`from_guild_id_and_str`], except an error is returned if
+    /// the data couldn't be fetched over the REST API, so a failed request
+    /// isn't mistaken for a missing model. `Ok(None)` is returned if nothing
+    /// matches `arg`.
+    ///
+    /// Requests that fail due to a server error or a network issue are
+    /// retried a couple of times before giving up.
+    ///
+    /// The default implementation never returns an error.
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_guild_id_and_str(ctx, guild_id, arg).await)
+    }
+}
+
+/// Options to tweak how [`Conversion`] matches names.
+///
+/// They are used by [`Conversion::from_guild_id_and_str_with`]. IDs and
+/// mentions are not affected.
+///
+/// ```
+/// # use serenity_utils::conversion::ConversionOptions;
+/// #
+/// let mut options = ConversionOptions::new();
+/// options.case_insensitive(true);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConversionOptions {
+    /// Whether names are compared ignoring case, so "general" matches a
+    /// channel named "General".
+    ///
+    /// Defaults to `false`.
+    pub case_insensitive: bool,
+}
+
+impl ConversionOptions {
+    /// Creates a [`ConversionOptions`] object with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether names are compared ignoring case.
+    pub fn case_insensitive(&mut self, setting: bool) -> &mut Self {
+        self.case_insensitive = setting;
+
+        self
+    }
+}
+
+/// How names are compared by the conversions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Matching {
+    /// Names must be equal.
+    Exact,
+    /// Names must be equal, ignoring case.
+    CaseInsensitive,
+    /// Names must be equal, start with the argument or contain it, ignoring
+    /// case.
+    Fuzzy,
+}
+
+impl Matching {
+    /// Returns how closely `name` matches `arg`, `0` being the closest, or
+    /// `None` if it doesn't match.
+    ///
+    /// With [`Matching::Fuzzy`], an exact match is closer than a match
+    /// ignoring case, which is closer than a prefix, which is closer than a
+    /// substring.
+    fn rank(self, name: &str, arg: &str) -> Option<u8> {
+        match self {
+            Matching::Exact => (name == arg).then_some(0),
+            Matching::CaseInsensitive => (name.to_lowercase() == arg.to_lowercase()).then_some(0),
+            Matching::Fuzzy => {
+                if name == arg {
+                    return Some(0);
+                }
+
+                let (name, arg) = (name.to_lowercase(), arg.to_lowercase());
+                if arg.is_empty() {
+                    None
+                } else if name == arg {
+                    Some(1)
+                } else if name.starts_with(&arg) {
+                    Some(2)
+                } else if name.contains(&arg) {
+                    Some(3)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+}
+
+impl From<ConversionOptions> for Matching {
+    fn from(options: ConversionOptions) -> Self {
+        if options.case_insensitive {
+            Matching::CaseInsensitive
+        } else {
+            Matching::Exact
+        }
+    }
+}
+
+/// Converts `arg` into `T`, using the guild of `msg`.
+///
+/// This is a shortcut for [`Conversion::from_message_and_str`], so the
+/// guild's ID doesn't have to be taken from the message first. `None` is
+/// returned if the message wasn't sent in a guild. The cache is used if the
+/// `cache` feature is enabled, like with [`Conversion::from_guild_id_and_str`].
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::{Message, Role}, prelude::Context};
+/// # #[allow(deprecated)]
+/// use serenity_utils::conversion::convert;
+///
+/// async fn role_info(ctx: &Context, msg: &Message, arg: &str) {
+/// #   #[allow(deprecated)]
+///     if let Some(role) = convert::<Role>(ctx, msg, arg).await {
+///         // Use the role.
+///     }
+/// }
+/// ```
+pub async fn convert<T: Conversion + Send>(
+    ctx: &Context,
+    msg: &Message,
+    arg: &str,
+) -> Option<T::Item> {
+    T::from_message_and_str(ctx, msg, arg).await
 }
 
+/// The keyword [`Conversion::from_message_and_str`] resolves to the invoking
+/// member.
+pub const ME_KEYWORD: &str = "me";
+
+/// The keyword [`Conversion::from_message_and_str`] resolves to the channel
+/// the command was invoked in.
+pub const HERE_KEYWORD: &str = "here";
+
 #[async_trait]
 impl Conversion for Role {
     type Item = Self;
@@ -133,7 +402,7 @@ impl Conversion for Role {
     {
         let roles = &guild.roles;
 
-        role_from_mapping(arg, roles).await
+        roles_from_mapping(arg, roles, Matching::Exact).await.into_iter().next()
     }
 
     async fn from_guild_id_and_str(
@@ -144,25 +413,54 @@ impl Conversion for Role {
     where
         Self: Sized,
     {
-        #[cfg(feature = "cache")]
-        {
-            if let Some(roles) = ctx.cache.guild_roles(guild_id) {
-                return role_from_mapping(arg, &roles).await;
-            }
-        }
+        Self::try_from_guild_id_and_str(ctx, guild_id, arg).await.ok().flatten()
+    }
 
-        // Get guild's roles using http requests.
-        let roles = ctx.http.get_guild_roles(guild_id.0).await.ok()?;
-        match arg.parse::<u64>() {
-            // `arg` is role ID.
-            Ok(id) => roles.iter().find(|r| r.id.0 == id).cloned(),
-            Err(_) => match utils::parse_role(arg) {
-                // `arg` is role mention.
-                Some(id) => roles.iter().find(|r| r.id.0 == id).cloned(),
-                // `arg` is role name.
-                None => roles.iter().find(|r| r.name == arg).cloned(),
-            },
-        }
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        let roles = roles_of(ctx, guild_id, arg, Matching::Exact).await?;
+
+        Ok(roles.into_iter().next())
+    }
+
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        roles_of(ctx, guild_id, arg, options.into()).await.ok()?.into_iter().next()
+    }
+
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        roles_of(ctx, guild_id, arg, Matching::Fuzzy).await.ok()?.into_iter().next()
+    }
+
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        roles_of(ctx, guild_id, arg, Matching::Exact).await.unwrap_or_default()
     }
 }
 
@@ -178,7 +476,7 @@ impl Conversion for Member {
     {
         let members = &guild.members;
 
-        member_from_mapping(arg, members).await
+        members_from_mapping(arg, members, Matching::Exact).await.into_iter().next()
     }
 
     async fn from_guild_id_and_str(
@@ -189,41 +487,96 @@ impl Conversion for Member {
     where
         Self: Sized,
     {
-        #[cfg(feature = "cache")]
-        {
-            if let Some(members) = ctx.cache.guild_field(guild_id, |g| g.members.clone()) {
-                return member_from_mapping(arg, &members).await;
-            }
-        }
+        Self::try_from_guild_id_and_str(ctx, guild_id, arg).await.ok().flatten()
+    }
 
-        let id = match arg.parse::<u64>() {
-            // `arg` is a user ID.
-            Ok(id) => id,
-            Err(_) => match utils::parse_username(arg) {
-                Some(id) => id,
-                None => return None,
-            },
-        };
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        let members = members_of(ctx, guild_id, arg, Matching::Exact).await?;
+
+        Ok(members.into_iter().next())
+    }
+
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        members_of(ctx, guild_id, arg, options.into()).await.ok()?.into_iter().next()
+    }
+
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        members_of(ctx, guild_id, arg, Matching::Fuzzy).await.ok()?.into_iter().next()
+    }
+
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        members_of(ctx, guild_id, arg, Matching::Exact).await.unwrap_or_default()
+    }
+
+    /// Converts `arg` into a [`Member`] object, resolving [`ME_KEYWORD`] to
+    /// the message's author.
+    async fn from_message_and_str(ctx: &Context, msg: &Message, arg: &str) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let guild_id = msg.guild_id?;
+
+        if arg.eq_ignore_ascii_case(ME_KEYWORD) {
+            return guild_id.member(ctx, msg.author.id).await.ok();
+        }
 
-        ctx.http.get_member(guild_id.0, id).await.ok()
+        Self::from_guild_id_and_str(ctx, guild_id, arg).await
     }
 }
 
 #[async_trait]
-impl Conversion for GuildChannel {
+impl Conversion for User {
     type Item = Self;
 
-    /// Converts `arg` into a [`GuildChannel`] object.
+    /// Converts `arg` into a [`User`] object, using the guild's members.
     #[cfg(feature = "cache")]
     async fn from_guild_and_str(guild: &Guild, arg: &str) -> Option<Self>
     where
         Self: Sized,
     {
-        let channels = &guild.channels;
+        let members = &guild.members;
 
-        channel_from_mapping(arg, channels).await
+        users_from_mapping(arg, members, Matching::Exact).await.into_iter().next()
     }
 
+    /// Converts `arg` into a [`User`] object.
+    ///
+    /// Unlike [`Member`], the user doesn't have to be in the guild if `arg` is
+    /// an ID or a mention. The user is taken from the cache if possible and
+    /// fetched over the REST API otherwise, which is useful to, for example,
+    /// unban a user.
+    ///
+    /// Names are only matched against the user names and tags of the guild's
+    /// cached members.
     async fn from_guild_id_and_str(
         ctx: &Context,
         guild_id: GuildId,
@@ -232,80 +585,904 @@ impl Conversion for GuildChannel {
     where
         Self: Sized,
     {
-        #[cfg(feature = "cache")]
-        {
-            if let Some(channels) = ctx.cache.guild_field(guild_id, |g| g.channels.clone()) {
-                return channel_from_mapping(arg, &channels).await;
-            }
-        }
+        Self::try_from_guild_id_and_str(ctx, guild_id, arg).await.ok().flatten()
+    }
 
-        // Get guild's roles using http requests.
-        let channels = ctx.http.get_channels(guild_id.0).await.ok()?;
-        match arg.parse::<u64>() {
-            // `arg` is channel ID.
-            Ok(id) => channels.iter().find(|c| c.id.0 == id).cloned(),
-            Err(_) => match utils::parse_channel(arg) {
-                // `arg` is channel mention.
-                Some(id) => channels.iter().find(|c| c.id.0 == id).cloned(),
-                // `arg` is channel name.
-                None => channels.iter().find(|c| c.name == arg).cloned(),
-            },
-        }
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        let users = users_of(ctx, guild_id, arg, Matching::Exact).await?;
+
+        Ok(users.into_iter().next())
     }
-}
 
-async fn role_from_mapping(arg: &str, roles: &HashMap<RoleId, Role>) -> Option<Role> {
-    match arg.parse::<u64>() {
-        // `arg` is a role ID.
-        Ok(id) => roles.get(&RoleId(id)).cloned(),
-        Err(_) => match utils::parse_role(arg) {
-            // `arg` is a role mention.
-            Some(id) => roles.get(&RoleId(id)).cloned(),
-            // `arg` is a role name.
-            None => roles.values().find(|r| r.name == arg).cloned(),
-        },
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        users_of(ctx, guild_id, arg, options.into()).await.ok()?.into_iter().next()
     }
-}
 
-async fn member_from_mapping(arg: &str, members: &HashMap<UserId, Member>) -> Option<Member> {
-    match arg.parse::<u64>() {
-        // `arg` is a user ID.
-        Ok(id) => members.get(&UserId(id)).cloned(),
-        Err(_) => match utils::parse_username(arg) {
-            // `arg` is a member mention.
-            Some(id) => members.get(&UserId(id)).cloned(),
-            // `arg` is a member's name or nickname.
-            None => members
-                .values()
-                .find(|m| {
-                    m.display_name().as_str() == arg || m.user.name == arg || m.user.tag() == arg
-                })
-                .cloned(),
-        },
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        users_of(ctx, guild_id, arg, Matching::Fuzzy).await.ok()?.into_iter().next()
+    }
+
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        users_of(ctx, guild_id, arg, Matching::Exact).await.unwrap_or_default()
     }
 }
 
-async fn channel_from_mapping(
-    arg: &str,
-    channels: &HashMap<ChannelId, Channel>,
-) -> Option<GuildChannel> {
-    let get_guild_channel = |channel| {
-        if let &Channel::Guild(ref c) = channel {
-            Some(c)
-        } else {
-            None
-        }
-    };
+#[async_trait]
+impl Conversion for GuildChannel {
+    type Item = Self;
 
-    match arg.parse::<u64>() {
-        // `arg` is a channel ID.
-        Ok(id) => channels.get(&ChannelId(id)).and_then(get_guild_channel),
-        Err(_) => match utils::parse_channel(arg) {
-            // `arg` is a channel mention.
-            Some(id) => channels.get(&ChannelId(id)).and_then(get_guild_channel),
-            // `arg` is a channel name.
-            None => channels.values().find_map(|c| get_guild_channel(c).filter(|c| c.name == arg)),
-        },
+    /// Converts `arg` into a [`GuildChannel`] object.
+    #[cfg(feature = "cache")]
+    async fn from_guild_and_str(guild: &Guild, arg: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let channels = &guild.channels;
+
+        let matching = Matching::Exact;
+
+        channels_from_mapping(arg, channels, None, matching).await.into_iter().next()
+    }
+
+    async fn from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        Self::try_from_guild_id_and_str(ctx, guild_id, arg).await.ok().flatten()
+    }
+
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        channel_of_kind(ctx, guild_id, arg, None, Matching::Exact).await
     }
-    .cloned()
+
+    /// Converts `arg` into a [`GuildChannel`] object, matching names as set in
+    /// `options`.
+    ///
+    /// A leading `#` is ignored unless names must match exactly.
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        channel_of_kind(ctx, guild_id, arg, None, options.into()).await.ok().flatten()
+    }
+
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        channel_of_kind(ctx, guild_id, arg, None, Matching::Fuzzy).await.ok().flatten()
+    }
+
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        let matching = Matching::Exact;
+
+        channels_of_kind(ctx, guild_id, arg, None, matching).await.unwrap_or_default()
+    }
+
+    /// Converts `arg` into a [`GuildChannel`] object, resolving
+    /// [`HERE_KEYWORD`] to the message's channel.
+    ///
+    /// Threads are not guild channels, so the keyword resolves to `None` in a
+    /// thread.
+    async fn from_message_and_str(ctx: &Context, msg: &Message, arg: &str) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let guild_id = msg.guild_id?;
+
+        if arg.eq_ignore_ascii_case(HERE_KEYWORD) {
+            let id = msg.channel_id.0.to_string();
+
+            return channel_of_kind(ctx, guild_id, &id, None, Matching::Exact).await.ok().flatten();
+        }
+
+        Self::from_guild_id_and_str(ctx, guild_id, arg).await
+    }
+}
+
+#[async_trait]
+impl Conversion for Emoji {
+    type Item = Self;
+
+    /// Converts `arg` into an [`Emoji`] object.
+    ///
+    /// `arg` can be an emoji ID, a custom emoji like `<:blob:1234>` or an
+    /// emoji name. Colons around a name, like `:blob:`, are ignored.
+    #[cfg(feature = "cache")]
+    async fn from_guild_and_str(guild: &Guild, arg: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let emojis = &guild.emojis;
+
+        emojis_from_mapping(arg, emojis, Matching::Exact).await.into_iter().next()
+    }
+
+    /// Converts `arg` into an [`Emoji`] object.
+    ///
+    /// `arg` can be an emoji ID, a custom emoji like `<:blob:1234>` or an
+    /// emoji name. Colons around a name, like `:blob:`, are ignored. Only the
+    /// guild's emojis are returned.
+    async fn from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        Self::try_from_guild_id_and_str(ctx, guild_id, arg).await.ok().flatten()
+    }
+
+    async fn try_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Result<Option<Self::Item>, Error>
+    where
+        Self: Sized,
+    {
+        let emojis = emojis_of(ctx, guild_id, arg, Matching::Exact).await?;
+
+        Ok(emojis.into_iter().next())
+    }
+
+    async fn from_guild_id_and_str_with(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        options: ConversionOptions,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        emojis_of(ctx, guild_id, arg, options.into()).await.ok()?.into_iter().next()
+    }
+
+    async fn from_guild_id_and_str_fuzzy(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        emojis_of(ctx, guild_id, arg, Matching::Fuzzy).await.ok()?.into_iter().next()
+    }
+
+    async fn all_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        emojis_of(ctx, guild_id, arg, Matching::Exact).await.unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Conversion for Webhook {
+    type Item = Self;
+
+    /// Always returns `None` as webhooks are not cached.
+    ///
+    /// Use [`from_guild_id_and_str`] instead.
+    ///
+    /// [`from_guild_id_and_str`]: Conversion::from_guild_id_and_str
+    #[cfg(feature = "cache")]
+    async fn from_guild_and_str(_guild: &Guild, _arg: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Converts `arg` into a [`Webhook`] object.
+    ///
+    /// `arg` can be a webhook URL, a webhook ID or a webhook name. Webhooks
+    /// are always fetched over the REST API. Resolving an ID or a name requires
+    /// the current user to have the `Manage Webhooks` permission.
+    ///
+    /// Webhooks that don't belong to the guild are not returned.
+    async fn from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let webhook = if let Some((id, token)) = parse_webhook_url(arg) {
+            // `arg` is a webhook URL.
+            ctx.http.get_webhook_with_token(id, token).await.ok()?
+        } else if let Ok(id) = arg.parse::<u64>() {
+            // `arg` is a webhook ID.
+            ctx.http.get_webhook(id).await.ok()?
+        } else {
+            // `arg` is a webhook name.
+            let webhooks = ctx.http.get_guild_webhooks(guild_id.0).await.ok()?;
+
+            return webhooks.into_iter().find(|w| w.name.as_deref() == Some(arg));
+        };
+
+        (webhook.guild_id == Some(guild_id)).then_some(webhook)
+    }
+}
+
+/// A trait to convert a string into a [`Member`] that can be moderated.
+///
+/// It is implemented for [`Member`] and is meant for moderation commands, like
+/// kick or ban, so that a member who can't be acted upon is never resolved.
+#[async_trait]
+pub trait ActionableMember {
+    /// Converts `arg` into a [`Member`] ranked below both `invoker` and the
+    /// current user/bot.
+    ///
+    /// `arg` is resolved like [`Conversion::from_guild_id_and_str`]. `None` is
+    /// returned if no member is found or if the member's top role is not lower
+    /// than the top roles of both `invoker` and the current user/bot. The
+    /// guild owner is never returned, while an invoker or current user who
+    /// owns the guild outranks everyone else.
+    ///
+    /// The guild's roles are taken from the cache if the `cache` feature is
+    /// enabled and the guild is cached (this requires the `GUILDS` intent).
+    /// Otherwise, they are fetched over the REST API, along with the current
+    /// user's member.
+    async fn from_guild_id_and_str_actionable(
+        ctx: &Context,
+        guild_id: GuildId,
+        invoker: &Member,
+        arg: &str,
+    ) -> Option<Member>;
+}
+
+#[async_trait]
+impl ActionableMember for Member {
+    async fn from_guild_id_and_str_actionable(
+        ctx: &Context,
+        guild_id: GuildId,
+        invoker: &Member,
+        arg: &str,
+    ) -> Option<Member> {
+        let member = Member::from_guild_id_and_str(ctx, guild_id, arg).await?;
+
+        #[cfg(feature = "cache")]
+        let guild_data = ctx.cache.guild_field(guild_id, |g| (g.owner_id, g.roles.clone()));
+        #[cfg(not(feature = "cache"))]
+        let guild_data = None;
+
+        let (owner_id, roles) = match guild_data {
+            Some(data) => data,
+            None => {
+                let guild = guild_id.to_partial_guild(&ctx.http).await.ok()?;
+
+                (guild.owner_id, guild.roles)
+            },
+        };
+
+        #[cfg(feature = "cache")]
+        let current_user_id = ctx.cache.current_user_id();
+        #[cfg(not(feature = "cache"))]
+        let current_user_id = ctx.http.get_current_user().await.ok()?.id;
+
+        let current_member = guild_id.member(ctx, current_user_id).await.ok()?;
+
+        let outranks = |actor: &Member| {
+            actor.user.id == owner_id
+                || top_role_position(actor, &roles) > top_role_position(&member, &roles)
+        };
+
+        if member.user.id != owner_id && outranks(invoker) && outranks(&current_member) {
+            Some(member)
+        } else {
+            None
+        }
+    }
+}
+
+/// A trait to convert a string into a [`GuildChannel`] of a specific type.
+///
+/// It is implemented for [`GuildChannel`]. The argument is resolved like
+/// [`Conversion::from_guild_id_and_str`], but only channels of the requested
+/// type are considered, so a text channel is never confused with a voice
+/// channel of the same name. `None` is returned if the matched channel is of
+/// a different type.
+///
+/// Thread channels are not part of a guild's channels. They are only returned
+/// by [`thread_from_guild_id_and_str`].
+///
+/// [`thread_from_guild_id_and_str`]: ChannelConversion::thread_from_guild_id_and_str
+#[async_trait]
+pub trait ChannelConversion {
+    /// Converts `arg` into a channel of type `kind`.
+    ///
+    /// Channels of other types are skipped before matching, whether `arg` is
+    /// an ID, a mention or a name. The other methods of this trait are
+    /// shortcuts for common types.
+    async fn from_guild_id_and_str_of_kind(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        kind: ChannelType,
+    ) -> Option<GuildChannel>;
+
+    /// Converts `arg` into a text channel.
+    ///
+    /// Announcement (news) channels are not considered text channels.
+    async fn text_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel>;
+
+    /// Converts `arg` into a voice channel.
+    ///
+    /// Stage channels are not considered voice channels.
+    async fn voice_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel>;
+
+    /// Converts `arg` into a category.
+    async fn category_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel>;
+
+    /// Converts `arg` into an active thread.
+    ///
+    /// The guild's threads are taken from the cache if the `cache` feature is
+    /// enabled and the guild is cached. Otherwise, the guild's active threads
+    /// are fetched over the REST API, which costs a request per call.
+    ///
+    /// Archived threads are not searched.
+    async fn thread_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel>;
+}
+
+#[async_trait]
+impl ChannelConversion for GuildChannel {
+    async fn from_guild_id_and_str_of_kind(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+        kind: ChannelType,
+    ) -> Option<GuildChannel> {
+        let matching = Matching::Exact;
+
+        channel_of_kind(ctx, guild_id, arg, Some(kind), matching).await.ok().flatten()
+    }
+
+    async fn text_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel> {
+        Self::from_guild_id_and_str_of_kind(ctx, guild_id, arg, ChannelType::Text).await
+    }
+
+    async fn voice_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel> {
+        Self::from_guild_id_and_str_of_kind(ctx, guild_id, arg, ChannelType::Voice).await
+    }
+
+    async fn category_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel> {
+        Self::from_guild_id_and_str_of_kind(ctx, guild_id, arg, ChannelType::Category).await
+    }
+
+    async fn thread_from_guild_id_and_str(
+        ctx: &Context,
+        guild_id: GuildId,
+        arg: &str,
+    ) -> Option<GuildChannel> {
+        #[cfg(feature = "cache")]
+        let threads = ctx.cache.guild_field(guild_id, |g| g.threads.clone());
+        #[cfg(not(feature = "cache"))]
+        let threads = None;
+
+        let threads = match threads {
+            Some(threads) => threads,
+            None => fetch(|| ctx.http.get_guild_active_threads(guild_id.0)).await.ok()??.threads,
+        };
+
+        match arg.parse::<u64>() {
+            // `arg` is thread ID.
+            Ok(id) => threads.into_iter().find(|t| t.id.0 == id),
+            Err(_) => match utils::parse_channel(arg) {
+                // `arg` is thread mention.
+                Some(id) => threads.into_iter().find(|t| t.id.0 == id),
+                // `arg` is thread name.
+                None => threads.into_iter().find(|t| t.name == arg),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl Conversion for Colour {
+    type Item = Self;
+
+    /// Converts `arg` into a [`Colour`] object.
+    ///
+    /// The guild is not used. See [`parse_colour`] for the recognised formats.
+    #[cfg(feature = "cache")]
+    async fn from_guild_and_str(_guild: &Guild, arg: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        parse_colour(arg)
+    }
+
+    /// Converts `arg` into a [`Colour`] object.
+    ///
+    /// The guild is not used. See [`parse_colour`] for the recognised formats.
+    async fn from_guild_id_and_str(
+        _ctx: &Context,
+        _guild_id: GuildId,
+        arg: &str,
+    ) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        parse_colour(arg)
+    }
+}
+
+/// Returns the position of the member's highest role.
+///
+/// Members without roles have the position of the `@everyone` role, `0`.
+fn top_role_position(member: &Member, roles: &HashMap<RoleId, Role>) -> i64 {
+    member.roles.iter().filter_map(|id| roles.get(id)).map(|r| r.position).max().unwrap_or(0)
+}
+
+/// Resolves `arg` into the matching roles of the guild, sorted by ID.
+///
+/// The cache is used if it's enabled and the guild is cached. Otherwise, the
+/// roles are fetched over the REST API.
+async fn roles_of(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    matching: Matching,
+) -> Result<Vec<Role>, Error> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(roles) = ctx.cache.guild_roles(guild_id) {
+            return Ok(roles_from_mapping(arg, &roles, matching).await);
+        }
+    }
+
+    // Get guild's roles using http requests.
+    let roles = match fetch(|| ctx.http.get_guild_roles(guild_id.0)).await? {
+        Some(roles) => roles.into_iter().map(|r| (r.id, r)).collect(),
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(roles_from_mapping(arg, &roles, matching).await)
+}
+
+/// Resolves `arg` into the matching members of the guild, sorted by ID.
+///
+/// The cache is used if it's enabled and the guild is cached. Otherwise, the
+/// member is fetched over the REST API, which requires `arg` to be an ID or a
+/// mention.
+async fn members_of(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    matching: Matching,
+) -> Result<Vec<Member>, Error> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(members) = ctx.cache.guild_field(guild_id, |g| g.members.clone()) {
+            return Ok(members_from_mapping(arg, &members, matching).await);
+        }
+    }
+    #[cfg(not(feature = "cache"))]
+    let _ = matching;
+
+    let id = match arg.parse::<u64>() {
+        // `arg` is a user ID.
+        Ok(id) => id,
+        Err(_) => match utils::parse_username(arg) {
+            Some(id) => id,
+            None => return Ok(Vec::new()),
+        },
+    };
+
+    Ok(fetch(|| ctx.http.get_member(guild_id.0, id)).await?.into_iter().collect())
+}
+
+/// Resolves `arg` into the matching users, sorted by ID. A user doesn't have
+/// to be in the guild if `arg` is an ID or a mention.
+///
+/// Names are matched against the guild's cached members.
+async fn users_of(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    matching: Matching,
+) -> Result<Vec<User>, Error> {
+    // `arg` is a user ID or a user mention.
+    let id = arg.parse::<u64>().ok().or_else(|| utils::parse_username(arg));
+
+    #[cfg(feature = "cache")]
+    {
+        match id {
+            Some(id) => {
+                if let Some(user) = ctx.cache.user(id) {
+                    return Ok(vec![user]);
+                }
+            },
+            None => {
+                if let Some(members) = ctx.cache.guild_field(guild_id, |g| g.members.clone()) {
+                    return Ok(users_from_mapping(arg, &members, matching).await);
+                }
+            },
+        }
+    }
+    #[cfg(not(feature = "cache"))]
+    let _ = (guild_id, matching);
+
+    match id {
+        Some(id) => Ok(fetch(|| ctx.http.get_user(id)).await?.into_iter().collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+async fn roles_from_mapping(
+    arg: &str,
+    roles: &HashMap<RoleId, Role>,
+    matching: Matching,
+) -> Vec<Role> {
+    let found = match arg.parse::<u64>() {
+        // `arg` is a role ID.
+        Ok(id) => roles.get(&RoleId(id)).map(|r| (0, r.clone())).into_iter().collect(),
+        Err(_) => match utils::parse_role(arg) {
+            // `arg` is a role mention.
+            Some(id) => roles.get(&RoleId(id)).map(|r| (0, r.clone())).into_iter().collect(),
+            // `arg` is a role name.
+            None => roles
+                .values()
+                .filter_map(|r| Some((matching.rank(&r.name, arg)?, r.clone())))
+                .collect(),
+        },
+    };
+
+    sort_ranked(found, |r| r.id)
+}
+
+#[cfg(feature = "cache")]
+async fn members_from_mapping(
+    arg: &str,
+    members: &HashMap<UserId, Member>,
+    matching: Matching,
+) -> Vec<Member> {
+    let found = match arg.parse::<u64>() {
+        // `arg` is a user ID.
+        Ok(id) => members.get(&UserId(id)).map(|m| (0, m.clone())).into_iter().collect(),
+        Err(_) => match utils::parse_username(arg) {
+            // `arg` is a member mention.
+            Some(id) => members.get(&UserId(id)).map(|m| (0, m.clone())).into_iter().collect(),
+            // `arg` is a member's name or nickname.
+            None => members
+                .values()
+                .filter_map(|m| {
+                    let rank = [
+                        matching.rank(&m.display_name(), arg),
+                        matching.rank(&m.user.name, arg),
+                        matching.rank(&m.user.tag(), arg),
+                    ];
+
+                    Some((rank.into_iter().flatten().min()?, m.clone()))
+                })
+                .collect(),
+        },
+    };
+
+    sort_ranked(found, |m| m.user.id)
+}
+
+#[cfg(feature = "cache")]
+async fn users_from_mapping(
+    arg: &str,
+    members: &HashMap<UserId, Member>,
+    matching: Matching,
+) -> Vec<User> {
+    let found = match arg.parse::<u64>() {
+        // `arg` is a user ID.
+        Ok(id) => members.get(&UserId(id)).map(|m| (0, m.user.clone())).into_iter().collect(),
+        Err(_) => match utils::parse_username(arg) {
+            // `arg` is a user mention.
+            Some(id) => members.get(&UserId(id)).map(|m| (0, m.user.clone())).into_iter().collect(),
+            // `arg` is a user name or tag.
+            None => members
+                .values()
+                .filter_map(|m| {
+                    let rank =
+                        [matching.rank(&m.user.name, arg), matching.rank(&m.user.tag(), arg)];
+
+                    Some((rank.into_iter().flatten().min()?, m.user.clone()))
+                })
+                .collect(),
+        },
+    };
+
+    sort_ranked(found, |u| u.id)
+}
+
+/// Resolves `arg` into the matching emojis of the guild, sorted by ID.
+///
+/// The cache is used if it's enabled and the guild is cached. Otherwise, the
+/// emojis are fetched over the REST API.
+async fn emojis_of(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    matching: Matching,
+) -> Result<Vec<Emoji>, Error> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(emojis) = ctx.cache.guild_field(guild_id, |g| g.emojis.clone()) {
+            return Ok(emojis_from_mapping(arg, &emojis, matching).await);
+        }
+    }
+
+    // Get guild's emojis using http requests.
+    let emojis = match fetch(|| ctx.http.get_emojis(guild_id.0)).await? {
+        Some(emojis) => emojis.into_iter().map(|e| (e.id, e)).collect(),
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(emojis_from_mapping(arg, &emojis, matching).await)
+}
+
+async fn emojis_from_mapping(
+    arg: &str,
+    emojis: &HashMap<EmojiId, Emoji>,
+    matching: Matching,
+) -> Vec<Emoji> {
+    let found = match arg.parse::<u64>() {
+        // `arg` is an emoji ID.
+        Ok(id) => emojis.get(&EmojiId(id)).map(|e| (0, e.clone())).into_iter().collect(),
+        Err(_) => match utils::parse_emoji(arg) {
+            // `arg` is a custom emoji.
+            Some(emoji) => emojis.get(&emoji.id).map(|e| (0, e.clone())).into_iter().collect(),
+            // `arg` is an emoji name, which may be surrounded by colons.
+            None => {
+                let name = arg.strip_prefix(':').and_then(|a| a.strip_suffix(':')).unwrap_or(arg);
+
+                emojis
+                    .values()
+                    .filter_map(|e| Some((matching.rank(&e.name, name)?, e.clone())))
+                    .collect()
+            },
+        },
+    };
+
+    sort_ranked(found, |e| e.id)
+}
+
+/// Sorts ranked candidates from the closest to the farthest match.
+///
+/// Candidates with the same rank are sorted by `key`, usually their ID, so the
+/// order doesn't depend on the order of a guild's mappings.
+fn sort_ranked<T, K: Ord>(mut found: Vec<(u8, T)>, key: impl Fn(&T) -> K) -> Vec<T> {
+    found.sort_by_key(|(rank, item)| (*rank, key(item)));
+
+    found.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Resolves `arg` into the matching guild channels, sorted by ID, considering
+/// only channels of `kind` if it is given.
+///
+/// The cache is used if it's enabled and the guild is cached. Otherwise, the
+/// channels are fetched over the REST API.
+async fn channels_of_kind(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    kind: Option<ChannelType>,
+    matching: Matching,
+) -> Result<Vec<GuildChannel>, Error> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(channels) = ctx.cache.guild_field(guild_id, |g| g.channels.clone()) {
+            return Ok(channels_from_mapping(arg, &channels, kind, matching).await);
+        }
+    }
+
+    // Get guild's channels using http requests.
+    let channels = match fetch(|| ctx.http.get_channels(guild_id.0)).await? {
+        Some(channels) => channels.into_iter().map(|c| (c.id, Channel::Guild(c))).collect(),
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(channels_from_mapping(arg, &channels, kind, matching).await)
+}
+
+/// Same as [`channels_of_kind`], but only returns the first match.
+async fn channel_of_kind(
+    ctx: &Context,
+    guild_id: GuildId,
+    arg: &str,
+    kind: Option<ChannelType>,
+    matching: Matching,
+) -> Result<Option<GuildChannel>, Error> {
+    Ok(channels_of_kind(ctx, guild_id, arg, kind, matching).await?.into_iter().next())
+}
+
+/// The number of times a request is made before giving up.
+const REQUEST_ATTEMPTS: u32 = 3;
+
+/// The time to wait before retrying a request. It grows with each attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Makes a request, retrying it if it fails due to a transient error.
+///
+/// Server errors and network issues are considered transient. `Ok(None)` is
+/// returned if Discord responds with `404 Not Found`.
+async fn fetch<T, F, Fut>(mut request: F) -> Result<Option<T>, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = serenity::Result<T>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        let error = match request().await {
+            Ok(value) => return Ok(Some(value)),
+            Err(SerenityError::Http(e)) => e,
+            Err(e) => return Err(e.into()),
+        };
+
+        let status = error.status_code();
+        if status == Some(StatusCode::NOT_FOUND) {
+            return Ok(None);
+        }
+
+        let transient =
+            status.is_some_and(|s| s.is_server_error()) || matches!(*error, HttpError::Request(_));
+        if !transient || attempt == REQUEST_ATTEMPTS {
+            return Err(SerenityError::Http(error).into());
+        }
+
+        debug!(attempt, "retrying failed conversion request");
+        tokio::time::sleep(RETRY_BACKOFF * attempt).await;
+        attempt += 1;
+    }
+}
+
+async fn channels_from_mapping(
+    arg: &str,
+    channels: &HashMap<ChannelId, Channel>,
+    kind: Option<ChannelType>,
+    matching: Matching,
+) -> Vec<GuildChannel> {
+    let get_guild_channel = |channel: &Channel| match channel {
+        Channel::Guild(c) if kind.map_or(true, |k| c.kind == k) => Some(c.clone()),
+        _ => None,
+    };
+
+    let found = match arg.parse::<u64>() {
+        // `arg` is a channel ID.
+        Ok(id) => channels
+            .get(&ChannelId(id))
+            .and_then(get_guild_channel)
+            .map(|c| (0, c))
+            .into_iter()
+            .collect(),
+        Err(_) => match utils::parse_channel(arg) {
+            // `arg` is a channel mention.
+            Some(id) => channels
+                .get(&ChannelId(id))
+                .and_then(get_guild_channel)
+                .map(|c| (0, c))
+                .into_iter()
+                .collect(),
+            // `arg` is a channel name. Loose matching allows a leading `#`,
+            // exact matching keeps the name as it is.
+            None => {
+                let name = match matching {
+                    Matching::Exact => arg,
+                    _ => arg.strip_prefix('#').unwrap_or(arg),
+                };
+
+                channels
+                    .values()
+                    .filter_map(|c| {
+                        let channel = get_guild_channel(c)?;
+
+                        Some((matching.rank(&channel.name, name)?, channel))
+                    })
+                    .collect()
+            },
+        },
+    };
+
+    sort_ranked(found, |c| c.id)
+}
+
+/// Extracts the webhook ID and token from a webhook URL of the form
+/// `.../webhooks/{id}/{token}`.
+fn parse_webhook_url(arg: &str) -> Option<(u64, &str)> {
+    let (_, path) = arg.split_once("/webhooks/")?;
+    let (id, token) = path.split_once('/')?;
+    let token = token.split(['?', '#']).next()?.trim_end_matches('/');
+
+    if token.is_empty() || token.contains('/') {
+        return None;
+    }
+
+    Some((id.parse().ok()?, token))
 }
@@ -2,12 +2,19 @@ use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::fmt::{self, Display, Formatter};
 
+use serenity::http::HttpError;
+use serenity::model::channel::ReactionType;
+use serenity::model::Permissions;
 use serenity::Error as SerenityError;
 
 /// A common error type for all functions and methods of the library.
 ///
 /// It can be directly converted into serenity's [`Error`](SerenityError).
+///
+/// New variants may be added in minor releases, so matching on it requires a
+/// wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Error returned by serenity.
     SerenityError(SerenityError),
@@ -15,10 +22,78 @@ pub enum Error {
     TimeoutError,
     /// Error returned when user's choice is invalid.
     InvalidChoice,
+    /// Error returned when the current user/bot is missing the contained
+    /// permissions.
+    MissingPermissions(Permissions),
+    /// Error returned when an operation is cancelled.
+    Cancelled,
+    /// Error returned when a user can't be sent direct messages.
+    ///
+    /// This usually means the user has disabled direct messages from server
+    /// members or has blocked the current user/bot.
+    DmsDisabled,
+    /// Error returned when some reactions couldn't be added to a message.
+    ///
+    /// It contains each reaction that failed along with the error returned
+    /// for it. The other reactions were added.
+    FailedReactions(Vec<(ReactionType, SerenityError)>),
     /// Error returned for all other cases.
     Other(String),
 }
 
+impl Error {
+    /// Creates an [`Error::Other`] with the given message.
+    ///
+    /// ```
+    /// # use serenity_utils::Error;
+    /// #
+    /// let error = Error::msg("Something went wrong.");
+    ///
+    /// assert_eq!(error.to_string(), "Something went wrong.");
+    /// ```
+    pub fn msg<S: Into<String>>(message: S) -> Self {
+        Self::Other(message.into())
+    }
+
+    /// Returns `true` if this is an [`Error::TimeoutError`].
+    ///
+    /// ```
+    /// # use serenity_utils::Error;
+    /// #
+    /// assert!(Error::TimeoutError.is_timeout());
+    /// assert!(!Error::InvalidChoice.is_timeout());
+    /// ```
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Error::TimeoutError)
+    }
+
+    /// Returns `true` if this is an [`Error::InvalidChoice`].
+    pub fn is_invalid_choice(&self) -> bool {
+        matches!(self, Error::InvalidChoice)
+    }
+
+    /// Returns the contained serenity error if this is an
+    /// [`Error::SerenityError`].
+    pub fn as_serenity(&self) -> Option<&SerenityError> {
+        match self {
+            Error::SerenityError(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Returns Discord's JSON error code if this is an error response from
+    /// Discord's API.
+    pub(crate) fn discord_code(&self) -> Option<isize> {
+        match self {
+            Error::SerenityError(SerenityError::Http(e)) => match e.as_ref() {
+                HttpError::UnsuccessfulRequest(response) => Some(response.error.code),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 impl StdError for Error {}
 
 impl Display for Error {
@@ -27,6 +102,14 @@ impl Display for Error {
             Error::SerenityError(e) => Cow::from(e.to_string()),
             Error::TimeoutError => Cow::from("You took too long to respond."),
             Error::InvalidChoice => Cow::from("Invalid choice!"),
+            Error::MissingPermissions(p) => Cow::from(format!("Missing permissions: {}", p)),
+            Error::Cancelled => Cow::from("The operation was cancelled."),
+            Error::DmsDisabled => Cow::from("I can't send direct messages to the user."),
+            Error::FailedReactions(failures) => {
+                let emojis = failures.iter().map(|(e, _)| e.to_string()).collect::<Vec<_>>();
+
+                Cow::from(format!("Failed to add reactions: {}", emojis.join(", ")))
+            },
             Error::Other(e) => Cow::from(e),
         };
 
@@ -51,3 +134,31 @@ impl From<SerenityError> for Error {
         Self::SerenityError(error)
     }
 }
+
+/// Returns early with an [`Error::Other`].
+///
+/// The arguments are formatted like [`format!`] to create the error's message.
+///
+/// ## Example
+///
+/// ```
+/// use serenity_utils::{bail, Error};
+///
+/// fn check_amount(amount: u32) -> Result<u32, Error> {
+///     if amount > 100 {
+///         bail!("The amount can't be more than 100, got {}.", amount);
+///     }
+///
+///     Ok(amount)
+/// }
+///
+/// assert!(check_amount(101).is_err());
+/// ```
+///
+/// [`Error::Other`]: crate::Error::Other
+#[macro_export]
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return ::std::result::Result::Err($crate::Error::msg(::std::format!($($arg)*)))
+    };
+}
@@ -5,7 +5,9 @@
 use std::borrow::Cow;
 use std::fmt::{Display, Write};
 
+use serenity::builder::CreateMessage;
 use serenity::model::channel::AttachmentType;
+use serenity::model::id::{ChannelId, CommandId, RoleId, UserId};
 
 /// A struct to set [`pagify`]'s options.
 ///
@@ -15,6 +17,8 @@ use serenity::model::channel::AttachmentType;
 /// - shorten_by: 0
 /// - page_length: 2000
 /// - priority: false
+/// - never_split_words: false
+/// - max_page_length: 2000
 ///
 /// The easiest way to build [`PagifyOptions`] is to use the builder-pattern:
 ///
@@ -45,6 +49,18 @@ pub struct PagifyOptions<'a> {
     ///
     /// It defaults to `false`.
     pub priority: bool,
+    /// If set to `true`, a page is never broken in the middle of a word.
+    ///
+    /// When no delimiter is found within `page_length`, the page is extended
+    /// up to the next delimiter instead, as long as the page doesn't become
+    /// longer than `max_page_length`.
+    ///
+    /// It defaults to `false`.
+    pub never_split_words: bool,
+    /// Hard maximum length of each page when `never_split_words` is `true`.
+    ///
+    /// Words longer than this are still broken. Defaults to `2000`.
+    pub max_page_length: usize,
 }
 
 impl<'a> PagifyOptions<'a> {
@@ -114,6 +130,36 @@ impl<'a> PagifyOptions<'a> {
 
         self
     }
+
+    /// Updates the `never_split_words` field.
+    ///
+    /// If `true`, a page that has no delimiter within `page_length` is
+    /// extended up to the next delimiter, so words are not split. Such pages
+    /// can be longer than `page_length` but never longer than
+    /// `max_page_length`. It defaults to `false`.
+    ///
+    /// Discord doesn't allow messages longer than 2000 characters. If you set
+    /// `max_page_length` above that, make sure the pages are sent in a way
+    /// that allows the extra length, like in an embed description.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn never_split_words(&mut self, setting: bool) -> &mut Self {
+        self.never_split_words = setting;
+
+        self
+    }
+
+    /// Updates the `max_page_length` field.
+    ///
+    /// This is the maximum length a page can have when `never_split_words` is
+    /// `true`. It defaults to `2000`.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn max_page_length(&mut self, length: usize) -> &mut Self {
+        self.max_page_length = length;
+
+        self
+    }
 }
 
 impl<'a> Default for PagifyOptions<'a> {
@@ -121,9 +167,11 @@ impl<'a> Default for PagifyOptions<'a> {
         Self {
             delims: &["\n", " "],
             escape_mass_mentions: true,
-            shorten_by: 8,
+            shorten_by: 0,
             page_length: 2000,
             priority: false,
+            never_split_words: false,
+            max_page_length: 2000,
         }
     }
 }
@@ -159,58 +207,115 @@ impl<'a> Default for PagifyOptions<'a> {
 /// This is ported from [`Red-DiscordBot's pagify`] function.
 ///
 /// [`Red-DiscordBot's pagify`]: https://github.com/Cog-Creators/Red-DiscordBot/blob/V3/develop/redbot/core/utils/chat_formatting.py#L212
-pub fn pagify<S: ToString>(text: S, mut options: PagifyOptions<'_>) -> Vec<String> {
+pub fn pagify<S: ToString>(text: S, options: PagifyOptions<'_>) -> Vec<String> {
     let text = text.to_string();
+
+    pagify_borrowed(&text, options).into_iter().map(Cow::into_owned).collect()
+}
+
+/// Same as [`pagify`], but borrows the pages from `text` when possible.
+///
+/// A page is only allocated if escaping mass mentions changes it. With
+/// `escape_mass_mentions` set to `false`, no page is allocated. This is useful
+/// when pagifying large amounts of text.
+///
+/// ## Example
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use serenity_utils::formatting::{pagify_borrowed, PagifyOptions};
+/// #
+/// let mut options = PagifyOptions::default();
+/// options.page_length(11).shorten_by(0).escape_mass_mentions(false);
+///
+/// let pages = pagify_borrowed("Hello world! Bye world!", options);
+///
+/// assert_eq!(pages, vec!["Hello", " world!", " Bye world!"]);
+/// assert!(pages.iter().all(|p| matches!(p, Cow::Borrowed(_))));
+/// ```
+pub fn pagify_borrowed<'a>(text: &'a str, mut options: PagifyOptions<'_>) -> Vec<Cow<'a, str>> {
     let mut in_text = text;
 
     let mut texts = Vec::new();
 
     options.page_length -= options.shorten_by;
     while in_text.len() > options.page_length {
-        let mut this_page_len = options.page_length;
+        let mut this_page_len = floor_char_boundary(in_text, options.page_length);
 
         if options.escape_mass_mentions {
-            let sliced_text = match in_text.get(0..options.page_length) {
-                Some(s) => s,
-                None => continue,
-            };
+            let sliced_text = &in_text[..this_page_len];
             this_page_len -=
                 sliced_text.matches("@here").count() + sliced_text.matches("@everyone").count();
+            this_page_len = floor_char_boundary(in_text, this_page_len);
         }
 
         let mut possible_delims = options
             .delims
             .iter()
-            .filter_map(|&d| in_text[1..this_page_len].rfind(d).map(|i| i + 1));
+            .filter_map(|&d| in_text[..this_page_len].rfind(d).filter(|&i| i > 0));
 
         let closest_delim =
-            if options.priority { possible_delims.find(|&d| d > 1) } else { possible_delims.max() }
-                .unwrap_or(this_page_len);
+            if options.priority { possible_delims.find(|&d| d > 1) } else { possible_delims.max() };
 
-        let to_send = if options.escape_mass_mentions {
-            escape_mass_mentions(&in_text[..closest_delim])
-        } else {
-            in_text[..closest_delim].to_string()
+        let closest_delim = match closest_delim {
+            Some(d) => d,
+            None if options.never_split_words => {
+                next_delim(in_text, this_page_len, &options).unwrap_or(this_page_len)
+            },
+            None => this_page_len,
         };
 
+        let to_send = &in_text[..closest_delim];
+
         if !to_send.is_empty() {
-            texts.push(to_send);
+            texts.push(escape_page(to_send, &options));
         }
 
-        in_text = in_text[closest_delim..].to_string();
+        in_text = &in_text[closest_delim..];
     }
 
     if !in_text.trim().is_empty() {
-        if options.escape_mass_mentions {
-            texts.push(escape_mass_mentions(in_text))
-        } else {
-            texts.push(in_text)
-        }
+        texts.push(escape_page(in_text, &options));
     }
 
     texts
 }
 
+/// Escapes mass mentions in a page if enabled, only allocating if needed.
+fn escape_page<'a>(page: &'a str, options: &PagifyOptions<'_>) -> Cow<'a, str> {
+    if options.escape_mass_mentions && (page.contains("@everyone") || page.contains("@here")) {
+        Cow::Owned(escape_mass_mentions(page))
+    } else {
+        Cow::Borrowed(page)
+    }
+}
+
+/// Returns the index of the first delimiter after `start`, if the page up to
+/// it fits in `max_page_length`. The whole text is returned as a page if it
+/// has no further delimiters and fits.
+fn next_delim(text: &str, start: usize, options: &PagifyOptions<'_>) -> Option<usize> {
+    let max_length = options.max_page_length.saturating_sub(options.shorten_by);
+
+    let end = options
+        .delims
+        .iter()
+        .filter_map(|&d| text[start..].find(d).map(|i| i + start))
+        .min()
+        .unwrap_or(text.len());
+
+    (end <= max_length).then_some(end)
+}
+
+/// Returns the largest index not greater than `index` that lies on a `char`
+/// boundary of `text`.
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
 /// Returns text after escaping mass mentions (@everyone and @here).
 ///
 /// A zero-width Unicode character (u200b) is added between `@` and `everyone` or `here`
@@ -224,6 +329,448 @@ pub fn escape_mass_mentions<S: ToString>(text: S) -> String {
     text.to_string().replace("@everyone", "@\u{200b}everyone").replace("@here", "@\u{200b}here")
 }
 
+/// Returns text with Discord's markdown removed.
+///
+/// Bold, italics, underline, strikethrough and spoiler markers, code fences
+/// and quote prefixes are removed, and backslash-escaped characters are
+/// unescaped. The content of code blocks is kept as it is, as Discord doesn't
+/// format it. Unbalanced markers are removed too.
+///
+/// Underscores inside words, like in `snake_case`, and single tildes are not
+/// treated as markers.
+///
+/// ```
+/// # use serenity_utils::formatting::strip_markdown;
+/// #
+/// let text = strip_markdown("> **Bold**, __underlined__ and `*code*`");
+///
+/// assert_eq!(text, "Bold, underlined and *code*");
+/// ```
+pub fn strip_markdown(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let starts_with = |i: usize, pattern: &str| {
+        pattern.chars().enumerate().all(|(j, c)| chars.get(i + j) == Some(&c))
+    };
+
+    let mut stripped = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut line_start = true;
+
+    while i < chars.len() {
+        if line_start {
+            line_start = false;
+
+            if starts_with(i, ">>> ") {
+                i += 4;
+                continue;
+            } else if starts_with(i, "> ") {
+                i += 2;
+                continue;
+            }
+        }
+
+        match chars[i] {
+            '\\' if chars.get(i + 1).is_some_and(|c| "\\*_~`|>".contains(*c)) => {
+                stripped.push(chars[i + 1]);
+                i += 2;
+            },
+            '`' => {
+                let fence = if starts_with(i, "```") { "```" } else { "`" };
+                let start = i + fence.len();
+                let end = (start..chars.len()).find(|&j| starts_with(j, fence));
+
+                match end {
+                    Some(end) => {
+                        let mut code = &chars[start..end];
+
+                        if fence.len() == 3 {
+                            // Skip the language of a code block.
+                            if let Some(newline) = code.iter().position(|&c| c == '\n') {
+                                if code[..newline].iter().all(|c| c.is_alphanumeric()) {
+                                    code = &code[newline + 1..];
+                                }
+                            }
+
+                            if code.last() == Some(&'\n') {
+                                code = &code[..code.len() - 1];
+                            }
+                        }
+
+                        stripped.extend(code);
+                        i = end + fence.len();
+                    },
+                    None => i = start,
+                }
+            },
+            '*' => i += 1,
+            '~' | '|' if chars.get(i + 1) == Some(&chars[i]) => i += 2,
+            '_' => {
+                let is_word = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric());
+
+                if i > 0 && is_word(chars.get(i - 1)) && is_word(chars.get(i + 1)) {
+                    stripped.push('_');
+                }
+
+                i += 1;
+            },
+            c => {
+                stripped.push(c);
+                line_start = c == '\n';
+                i += 1;
+            },
+        }
+    }
+
+    stripped
+}
+
+/// Returns text formatted as a Discord quote.
+///
+/// Every line of the text is prefixed with `> `, so multi-line text is quoted
+/// as a whole.
+///
+/// Quoting does not escape anything. Escape untrusted text before quoting it:
+///
+/// ```
+/// # use serenity_utils::formatting::{escape_mass_mentions, quote};
+/// #
+/// let quoted = quote(escape_mass_mentions("Hello, @everyone!\nBye!"));
+///
+/// assert_eq!(quoted, "> Hello, @\u{200b}everyone!\n> Bye!");
+/// ```
+pub fn quote<S: ToString>(text: S) -> String {
+    text.to_string().split('\n').map(|line| format!("> {}", line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Returns text formatted as a Discord block quote.
+///
+/// The text is prefixed with `>>> `, which quotes everything after it,
+/// including any following lines.
+///
+/// Like [`quote`], this does not escape anything. Use [`escape_mass_mentions`]
+/// on untrusted text first.
+pub fn block_quote<S: ToString>(text: S) -> String {
+    format!(">>> {}", text.to_string())
+}
+
+/// Returns the items formatted as a numbered list.
+///
+/// Each item is put on its own line, numbered from `1`. An empty string is
+/// returned if there are no items.
+///
+/// ```
+/// # use serenity_utils::formatting::ordered_list;
+/// #
+/// assert_eq!(ordered_list(["apples", "pears"]), "1. apples\n2. pears");
+/// ```
+pub fn ordered_list<I, S>(items: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Display,
+{
+    let mut list = String::new();
+
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            list.push('\n');
+        }
+
+        let _ = write!(list, "{}. {}", i + 1, item);
+    }
+
+    list
+}
+
+/// Returns the items formatted as a bulleted list.
+///
+/// Each item is put on its own line, prefixed with `•`. An empty string is
+/// returned if there are no items. Use [`unordered_list_with_bullet`] to use a
+/// different bullet.
+///
+/// ```
+/// # use serenity_utils::formatting::unordered_list;
+/// #
+/// assert_eq!(unordered_list(["apples", "pears"]), "• apples\n• pears");
+/// ```
+pub fn unordered_list<I, S>(items: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Display,
+{
+    unordered_list_with_bullet(items, "•")
+}
+
+/// Returns the items formatted as a list with the given bullet.
+///
+/// This is the same as [`unordered_list`], except each item is prefixed with
+/// `bullet`. Note that Discord renders lines starting with `- ` or `* ` as a
+/// markdown list.
+pub fn unordered_list_with_bullet<I, S, B>(items: I, bullet: B) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Display,
+    B: Display,
+{
+    let mut list = String::new();
+
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            list.push('\n');
+        }
+
+        let _ = write!(list, "{} {}", bullet, item);
+    }
+
+    list
+}
+
+/// Returns the count followed by the singular or plural form of a word.
+///
+/// The singular form is used if `count` is `1` or `-1`. Otherwise, `plural`
+/// is used, or `singular` followed by `s` if `plural` is `None`. The count is
+/// formatted with [`humanize_number`].
+///
+/// ```
+/// # use serenity_utils::formatting::pluralize;
+/// #
+/// assert_eq!(pluralize(1, "warning", None), "1 warning");
+/// assert_eq!(pluralize(2, "warning", None), "2 warnings");
+/// assert_eq!(pluralize(1500, "child", Some("children")), "1,500 children");
+/// ```
+pub fn pluralize(count: i64, singular: &str, plural: Option<&str>) -> String {
+    let word = match (count.unsigned_abs(), plural) {
+        (1, _) => Cow::Borrowed(singular),
+        (_, Some(plural)) => Cow::Borrowed(plural),
+        (_, None) => Cow::Owned(format!("{}s", singular)),
+    };
+
+    format!("{} {}", humanize_number(count), word)
+}
+
+/// Returns the number with commas separating every three digits.
+///
+/// ```
+/// # use serenity_utils::formatting::humanize_number;
+/// #
+/// assert_eq!(humanize_number(1234567), "1,234,567");
+/// assert_eq!(humanize_number(-1000), "-1,000");
+/// assert_eq!(humanize_number(999), "999");
+/// ```
+pub fn humanize_number(number: i64) -> String {
+    let digits = number.unsigned_abs().to_string();
+    let mut humanized = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+    if number < 0 {
+        humanized.push('-');
+    }
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            humanized.push(',');
+        }
+
+        humanized.push(digit);
+    }
+
+    humanized
+}
+
+/// A struct to set [`table_with_options`]'s options.
+///
+/// The default options are:
+/// - code_block: true
+/// - max_width: None
+#[derive(Clone, Copy, Debug)]
+pub struct TableOptions {
+    /// Whether to wrap the table in a code block so it is displayed in a
+    /// monospace font.
+    ///
+    /// Defaults to `true`.
+    pub code_block: bool,
+    /// Maximum width of each line of the table.
+    ///
+    /// The widest columns are narrowed until the lines fit, and cells that
+    /// don't fit are truncated with `…`. Around 40 characters fit on most
+    /// phones without wrapping. No limit is applied if it's `None`.
+    ///
+    /// Defaults to `None`.
+    pub max_width: Option<usize>,
+}
+
+impl TableOptions {
+    /// Creates a [`TableOptions`] object with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the `code_block` field.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn code_block(&mut self, setting: bool) -> &mut Self {
+        self.code_block = setting;
+
+        self
+    }
+
+    /// Updates the `max_width` field.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn max_width(&mut self, width: usize) -> &mut Self {
+        self.max_width = Some(width);
+
+        self
+    }
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            code_block: true,
+            max_width: None,
+        }
+    }
+}
+
+/// Returns the rows formatted as a table with aligned columns, wrapped in a
+/// code block.
+///
+/// See [`table_with_options`] for more details.
+///
+/// ```
+/// # use serenity_utils::formatting::{table, TableOptions};
+/// #
+/// let rows = vec![vec!["alice".to_string(), "120".to_string()], vec![
+///     "bob".to_string(),
+///     "95".to_string(),
+/// ]];
+///
+/// assert_eq!(
+///     table(&["Name", "Score"], &rows),
+///     "```\nName  | Score\n------+------\nalice | 120\nbob   | 95\n```"
+/// );
+/// ```
+pub fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    table_with_options(headers, rows, TableOptions::default())
+}
+
+/// Returns the rows formatted as a table with aligned columns.
+///
+/// The header is separated from the rows by a line of dashes, and columns are
+/// separated by `|`. Rows with fewer cells than there are columns are padded
+/// with empty cells. Trailing whitespace is removed from every line.
+///
+/// Column widths are measured in characters, so cells with wide characters,
+/// like emojis, may not line up.
+pub fn table_with_options(headers: &[&str], rows: &[Vec<String>], options: TableOptions) -> String {
+    const SEPARATOR: &str = " | ";
+
+    let columns = rows.iter().map(Vec::len).chain(Some(headers.len())).max().unwrap_or(0);
+    let cell = |row: &[String], i: usize| row.get(i).map_or("", String::as_str).to_string();
+
+    let header = (0..columns).map(|i| headers.get(i).copied().unwrap_or("").to_string());
+    let lines = Some(header.collect::<Vec<_>>())
+        .into_iter()
+        .chain(rows.iter().map(|r| (0..columns).map(|i| cell(r, i)).collect()))
+        .collect::<Vec<_>>();
+
+    let mut widths = vec![0; columns];
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    if let Some(max_width) = options.max_width {
+        let separators = SEPARATOR.len() * columns.saturating_sub(1);
+
+        while widths.iter().sum::<usize>() + separators > max_width {
+            match widths.iter_mut().filter(|w| **w > 1).max() {
+                Some(widest) => *widest -= 1,
+                None => break,
+            }
+        }
+    }
+
+    let format_line = |line: &[String]| {
+        let cells = line.iter().zip(&widths).map(|(cell, &width)| {
+            let cell = if cell.chars().count() > width {
+                cell.chars().take(width - 1).chain(Some('…')).collect()
+            } else {
+                cell.clone()
+            };
+
+            format!("{:width$}", cell, width = width)
+        });
+
+        cells.collect::<Vec<_>>().join(SEPARATOR).trim_end().to_string()
+    };
+
+    let mut table = Vec::with_capacity(lines.len() + 1);
+    table.push(format_line(&lines[0]));
+    table.push(widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("-+-"));
+    table.extend(lines[1..].iter().map(|l| format_line(l)));
+
+    let table = table.join("\n");
+
+    if options.code_block {
+        format!("```\n{}\n```", table)
+    } else {
+        table
+    }
+}
+
+/// Returns the markup to mention a user.
+///
+/// ```
+/// # use serenity::model::id::UserId;
+/// # use serenity_utils::formatting::mention_user;
+/// #
+/// assert_eq!(mention_user(UserId(7)), "<@7>");
+/// ```
+pub fn mention_user(id: UserId) -> String {
+    format!("<@{}>", id.0)
+}
+
+/// Returns the markup to mention a role.
+///
+/// ```
+/// # use serenity::model::id::RoleId;
+/// # use serenity_utils::formatting::mention_role;
+/// #
+/// assert_eq!(mention_role(RoleId(7)), "<@&7>");
+/// ```
+pub fn mention_role(id: RoleId) -> String {
+    format!("<@&{}>", id.0)
+}
+
+/// Returns the markup to mention a channel.
+///
+/// ```
+/// # use serenity::model::id::ChannelId;
+/// # use serenity_utils::formatting::mention_channel;
+/// #
+/// assert_eq!(mention_channel(ChannelId(7)), "<#7>");
+/// ```
+pub fn mention_channel(id: ChannelId) -> String {
+    format!("<#{}>", id.0)
+}
+
+/// Returns the markup to mention a slash command.
+///
+/// `name` is the command's full name. For subcommands, it includes the parent
+/// command's name, like `"settings prefix"`. Clicking the mention fills in the
+/// command.
+///
+/// ```
+/// # use serenity::model::id::CommandId;
+/// # use serenity_utils::formatting::mention_slash_command;
+/// #
+/// assert_eq!(mention_slash_command("ping", CommandId(7)), "</ping:7>");
+/// ```
+pub fn mention_slash_command<S: Display>(name: S, id: CommandId) -> String {
+    format!("</{}:{}>", name, id.0)
+}
+
 /// Creates serenity's [`AttachmentType`] from the given text.
 ///
 /// If `file_name` is not specified, `file.txt` is used as the default.
@@ -251,3 +798,106 @@ pub fn text_to_file<'a, S: ToString, T: Display>(
         filename: qualified_file_name,
     }
 }
+
+/// Splits text into several text files that are at most `max_bytes` long.
+///
+/// This is useful for text that is too big to be uploaded as a single file,
+/// see [`text_to_file`]. Files are named `{base_name}.1.txt`,
+/// `{base_name}.2.txt` and so on, even if there is only one file. No files
+/// are created for empty text.
+///
+/// Files are split after the last complete line that fits. A line that
+/// doesn't fit in a file by itself is split between characters.
+///
+/// ```
+/// # use serenity_utils::formatting::text_to_files;
+/// #
+/// let files = text_to_files("first line\nsecond line\n", "log", 16);
+///
+/// assert_eq!(files.len(), 2);
+/// ```
+pub fn text_to_files<'a, T: Display>(
+    text: &str,
+    base_name: T,
+    max_bytes: usize,
+) -> Vec<AttachmentType<'a>> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if rest.len() <= max_bytes {
+            parts.push(rest);
+            break;
+        }
+
+        let mut end = max_bytes;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        if let Some(newline) = rest[..end].rfind('\n') {
+            end = newline + 1;
+        } else if end == 0 {
+            // Not even a single character fits, so it's used anyway.
+            end = rest.chars().next().map_or(1, char::len_utf8);
+        }
+
+        let (part, remaining) = rest.split_at(end);
+        parts.push(part);
+        rest = remaining;
+    }
+
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, part)| AttachmentType::Bytes {
+            data: Cow::from(part.as_bytes().to_vec()),
+            filename: format!("{}.{}.txt", base_name, i + 1),
+        })
+        .collect()
+}
+
+/// Creates a [`CreateMessage`] with the given text attached as a file.
+///
+/// The message's content is set to `content`, if any. The file is created
+/// with [`text_to_file`], so `file_name` and `spoiler` work the same way.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::Message, prelude::Context};
+/// # use serenity_utils::{formatting::message_with_text_file, Error};
+/// #
+/// async fn export(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let message = message_with_text_file(
+///         Some("Here's your export!".to_string()),
+///         "id,name\n1,Arius",
+///         Some("export.csv"),
+///         false,
+///     );
+///
+///     msg.channel_id
+///         .send_message(&ctx.http, |m| {
+///             *m = message;
+///
+///             m
+///         })
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub fn message_with_text_file<'a, S: ToString, T: Display>(
+    content: Option<String>,
+    text: S,
+    file_name: Option<T>,
+    spoiler: bool,
+) -> CreateMessage<'a> {
+    let mut message = CreateMessage::default();
+    if let Some(content) = content {
+        message.content(content);
+    }
+    message.add_file(text_to_file(text, file_name, spoiler));
+
+    message
+}
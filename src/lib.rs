@@ -10,6 +10,11 @@
 //! See module level documentation for in-depth info about the utilities
 //! provided by this crate.
 //!
+//! ## Features
+//!
+//! - `tracing`: Emits [`tracing`] spans and debug events for menus and prompts.
+//!   It is disabled by default.
+//!
 //! ## Installation and Usage
 //!
 //! To use this crate, add the following to your `Cargo.toml`:
@@ -27,9 +32,20 @@
 //!
 //! [`serenity`]: https://github.com/serenity-rs/serenity
 //! [`examples`]: https://github.com/AriusX7/serenity-utils/tree/current/examples
+//! [`tracing`]: https://docs.rs/tracing
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+/// Emits a `tracing` debug event if the `tracing` feature is enabled.
+///
+/// It expands to nothing otherwise.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
 #[deprecated(since = "0.7.0", note = "please use serenity's builders directly")]
 pub mod builder;
 #[deprecated(since = "0.7.0", note = "please use serenity's ArgumentConvert trait")]
@@ -5,6 +5,17 @@
 //! - move to next page
 //! - close menu
 //!
+//! A [`close_menu_keep`] control function is also provided to close the menu
+//! without deleting its message, and [`jump_to_page`] asks the user for the
+//! page to move to.
+//!
+//! [`ButtonMenu`] works like [`Menu`] but uses buttons instead of reactions,
+//! so it can run in channels where the bot can't manage reactions.
+//!
+//! [`select_menu`] builds on the menu to let a user pick an item from a
+//! paginated list, and [`paginated_message`] shows a long text with
+//! navigation.
+//!
 //! These functions are exposed publicly to allow customisation.
 //! Custom control functions can also be used with a menu.
 //! For more information, see [`Menu`].
@@ -15,23 +26,74 @@
 //! [`Red-DiscordBot`]: https://github.com/Cog-Creators/Red-DiscordBot/
 //! [`menu`]: https://github.com/Cog-Creators/Red-DiscordBot/blob/46eb9ce7a0bcded991af02665fec39fcb542c76d/redbot/core/utils/menus.py#L17
 
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
-use serenity::builder::CreateMessage;
+use serenity::async_trait;
+use serenity::builder::{CreateEmbed, CreateMessage};
 use serenity::collector::ReactionAction;
 use serenity::futures::StreamExt;
+use serenity::json::prelude::from_value;
+use serenity::json::{json, Value};
 use serenity::model::prelude::{Message, Reaction, ReactionType};
 use serenity::prelude::Context;
+use serenity::utils::Colour;
 
-use crate::misc::add_reactions;
+use crate::formatting::{pagify_borrowed, PagifyOptions};
+use crate::misc::{add_reactions_with_delay, try_add_reactions_blocking_with_delay};
+use crate::prompt::message_prompt_content;
 use crate::Error;
 
+mod button;
+
+#[doc(inline)]
+pub use button::*;
+
 /// Result variant for menu methods.
 pub type MenuResult = Result<(), Error>;
 
+/// The reason a menu stopped running.
+///
+/// It is returned by [`Menu::run_with_reason`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MenuEndReason {
+    /// The menu was closed by a control function.
+    Closed,
+    /// The user didn't react or click a button within the menu's timeout.
+    TimedOut,
+    /// The menu's message was deleted while the menu was running.
+    MessageDeleted,
+}
+
+/// What to do with a menu's message when the menu times out or stops because
+/// of an invalid choice or an error.
+///
+/// See [`MenuOptions::cleanup`] for more details.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CleanupBehavior {
+    /// Delete the menu's message.
+    DeleteMessage,
+    /// Delete all reactions on the menu's message, including reactions of
+    /// other users. This requires the `Manage Messages` permission.
+    #[default]
+    DeleteAllReactions,
+    /// Delete only the current user's/bot's control reactions. Reactions of
+    /// other users are left as they are.
+    DeleteOwnReactions,
+    /// Leave the menu's message as it is.
+    Nothing,
+}
+
+/// Discord's error code for an unknown message.
+const UNKNOWN_MESSAGE: isize = 10008;
+
 /// A fully functioning reaction-based menu.
 ///
 /// A reaction menu is a paginated message where the user can use reactions to
@@ -83,9 +145,23 @@ pub struct Menu<'a> {
     /// The invocation message.
     pub msg: &'a Message,
     /// The pages of the menu.
-    pub pages: &'a [CreateMessage<'a>],
+    ///
+    /// The pages are borrowed when the menu is created with [`Menu::new`] and
+    /// owned when it is created with [`Menu::new_from`]. They are empty when the
+    /// menu is created with [`Menu::new_with_provider`].
+    ///
+    /// A page can have several embeds. Styling from the options, like
+    /// [`MenuOptions::default_colour`], is applied to each of them.
+    pub pages: Cow<'a, [CreateMessage<'a>]>,
     /// The menu options.
     pub options: MenuOptions,
+    closed: bool,
+    result: Option<Box<dyn Any + Send + Sync>>,
+    edits_message: bool,
+    existing_checked: bool,
+    last_sent: Option<HashMap<&'static str, Value>>,
+    provider: Option<Box<dyn PageProvider<'a> + 'a>>,
+    rendered: HashMap<usize, CreateMessage<'a>>,
 }
 
 impl<'a> Menu<'a> {
@@ -96,24 +172,216 @@ impl<'a> Menu<'a> {
         pages: &'a [CreateMessage<'a>],
         options: MenuOptions,
     ) -> Self {
+        Self::with_pages(ctx, msg, Cow::Borrowed(pages), options)
+    }
+
+    /// Creates a new [`Menu`] object from anything that can be turned into
+    /// pages.
+    ///
+    /// Each item of `pages` is converted into a page with [`IntoPage`]. Unlike
+    /// [`Menu::new`], the menu owns its pages.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use serenity::{model::prelude::Message, prelude::Context};
+    /// use serenity_utils::menu::{Menu, MenuOptions};
+    /// use serenity_utils::Error;
+    ///
+    /// async fn use_menu(ctx: &Context, msg: &Message) -> Result<(), Error> {
+    ///     let pages = vec!["Page number one!", "Page number two!"];
+    ///
+    ///     let menu = Menu::new_from(ctx, msg, pages, MenuOptions::default());
+    ///     let opt_message = menu.run().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_from<P: IntoPage<'a>>(
+        ctx: &'a Context,
+        msg: &'a Message,
+        pages: Vec<P>,
+        options: MenuOptions,
+    ) -> Self {
+        let pages = pages.into_iter().map(IntoPage::into_page).collect::<Vec<_>>();
+
+        Self::with_pages(ctx, msg, Cow::Owned(pages), options)
+    }
+
+    /// Creates a new [`Menu`] object whose pages are generated by `provider`.
+    ///
+    /// A page is only requested from the provider when it is first shown.
+    /// Generated pages are kept, so moving back to a page doesn't request it
+    /// again.
+    pub fn new_with_provider<P: PageProvider<'a> + 'a>(
+        ctx: &'a Context,
+        msg: &'a Message,
+        provider: P,
+        options: MenuOptions,
+    ) -> Self {
+        let mut menu = Self::with_pages(ctx, msg, Cow::Owned(Vec::new()), options);
+        menu.provider = Some(Box::new(provider));
+
+        menu
+    }
+
+    /// Creates a new [`Menu`] object with pages made by splitting `text` with
+    /// [`pagify`].
+    ///
+    /// Each page is used as the content of a message. If `code_block` is set,
+    /// each page is wrapped in a code block with that language, which can be
+    /// empty. The pages are shortened to leave room for the code block's
+    /// markers.
+    ///
+    /// See [`paginated_message`] to create and run the menu at once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use serenity::{model::prelude::Message, prelude::Context};
+    /// use serenity_utils::formatting::PagifyOptions;
+    /// use serenity_utils::menu::{Menu, MenuOptions};
+    /// use serenity_utils::Error;
+    ///
+    /// async fn show_text(ctx: &Context, msg: &Message, text: &str) -> Result<(), Error> {
+    ///     let menu =
+    ///         Menu::from_text(ctx, msg, text, None, PagifyOptions::new(), MenuOptions::default());
+    ///     let opt_message = menu.run().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`pagify`]: crate::formatting::pagify
+    /// [`paginated_message`]: paginated_message()
+    pub fn from_text(
+        ctx: &'a Context,
+        msg: &'a Message,
+        text: &str,
+        code_block: Option<&str>,
+        mut pagify_options: PagifyOptions<'_>,
+        options: MenuOptions,
+    ) -> Self {
+        if let Some(language) = code_block {
+            // "```language\n" and "\n```" surround each page.
+            pagify_options.shorten_by += language.len() + 8;
+        }
+
+        let pages = pagify_borrowed(text, pagify_options)
+            .into_iter()
+            .map(|page| match code_block {
+                Some(language) => format!("```{}\n{}\n```", language, page),
+                None => page.into_owned(),
+            })
+            .collect::<Vec<_>>();
+
+        Self::new_from(ctx, msg, pages, options)
+    }
+
+    fn with_pages(
+        ctx: &'a Context,
+        msg: &'a Message,
+        pages: Cow<'a, [CreateMessage<'a>]>,
+        options: MenuOptions,
+    ) -> Self {
+        let edits_message = options.message.is_some();
+
         Self {
             ctx,
             msg,
             pages,
             options,
+            closed: false,
+            result: None,
+            edits_message,
+            existing_checked: false,
+            last_sent: None,
+            provider: None,
+            rendered: HashMap::new(),
+        }
+    }
+
+    /// Creates a menu that splits `fields` into pages of embeds and runs it.
+    ///
+    /// Each page has an embed with `title` and up to `per_page` fields. The
+    /// embed's footer shows the page number, like "Page 1/2". Discord allows
+    /// up to 25 fields per embed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use serenity::{model::prelude::Message, prelude::Context};
+    /// use serenity_utils::builder::embed::EmbedFieldBuilder;
+    /// use serenity_utils::menu::{Menu, MenuOptions};
+    /// use serenity_utils::Error;
+    ///
+    /// async fn use_menu(ctx: &Context, msg: &Message) -> Result<(), Error> {
+    ///     let fields = (1..=40)
+    ///         .map(|n| EmbedFieldBuilder::new(format!("Field {}", n), "Value", true))
+    ///         .collect();
+    ///
+    ///     Menu::from_fields(ctx, msg, "Fields", fields, 20, MenuOptions::default()).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Other`] if `per_page` is not between 1 and 25 or if
+    /// `fields` is empty. Other errors are the same as [`run`].
+    ///
+    /// [`run`]: Menu::run
+    /// [`Error::Other`]: crate::error::Error::Other
+    #[allow(deprecated)]
+    pub async fn from_fields(
+        ctx: &Context,
+        msg: &Message,
+        title: &str,
+        fields: Vec<crate::builder::embed::EmbedFieldBuilder>,
+        per_page: usize,
+        options: MenuOptions,
+    ) -> Result<Option<Message>, Error> {
+        use crate::builder::embed::{EmbedBuilder, EmbedFooterBuilder};
+
+        if !(1..=25).contains(&per_page) {
+            return Err(Error::msg("`per_page` must be between 1 and 25."));
         }
+
+        let total = fields.len().div_ceil(per_page);
+        let embeds = fields
+            .chunks(per_page)
+            .enumerate()
+            .map(|(page, chunk)| {
+                let mut embed = EmbedBuilder::new();
+                embed.set_title(title).set_footer(EmbedFooterBuilder::new(format!(
+                    "Page {}/{}",
+                    page + 1,
+                    total
+                )));
+                embed.fields = chunk.to_vec();
+
+                embed
+            })
+            .collect::<Vec<_>>();
+
+        Menu::new_from(ctx, msg, embeds, options).run().await
     }
 
     /// Runs the reaction menu.
     ///
     /// It returns the message used to display the reaction menu after running.
+    /// If the message is deleted while the menu is running, the menu stops
+    /// and `None` is returned. See [`run_with_reason`] to know why the menu
+    /// stopped.
     ///
     /// ## Errors
     ///
     /// Returns [`Error::SerenityError`] if
     /// - current user/bot doesn't have the permissions to add reactions
     /// - `msg` is specified in [`MenuOptions`] but the current user/bot isn't
-    ///     the author of the message
+    ///   the author of the message
     /// - the message content lengths are over Discord's limit
     /// - current user/bot doesn't have the permissions to send an message/embed
     ///
@@ -128,48 +396,196 @@ impl<'a> Menu<'a> {
     /// [`Error::InvalidChoice`]: crate::error::Error::InvalidChoice
     /// [`Error::Other`]: crate::error::Error::Other
     /// [`control`]: Control
-    pub async fn run(mut self) -> Result<Option<Message>, Error> {
+    /// [`run_with_reason`]: Menu::run_with_reason
+    pub async fn run(self) -> Result<Option<Message>, Error> {
+        self.run_with_reason().await.map(|(message, _)| message)
+    }
+
+    /// Runs the reaction menu and returns why it stopped.
+    ///
+    /// It returns the message used to display the reaction menu along with
+    /// the [`MenuEndReason`]. If the menu's message is deleted while the menu
+    /// is running, the menu stops without an error and `None` is returned in
+    /// place of the message.
+    ///
+    /// ## Errors
+    ///
+    /// The errors are the same as [`run`].
+    ///
+    /// [`run`]: Menu::run
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(channel_id = %self.msg.channel_id))
+    )]
+    pub async fn run_with_reason(mut self) -> Result<(Option<Message>, MenuEndReason), Error> {
+        self.start().await?;
+
+        let reason = self.run_loop().await?;
+        self.end(reason).await;
+
+        Ok((self.options.message, reason))
+    }
+
+    /// Runs the reaction menu until a control function sets a result.
+    ///
+    /// A control function ends the menu with a result by calling
+    /// [`set_result`]. The result is returned if it is of type `T`. `None` is
+    /// returned if the menu ends without a result, for example if it is
+    /// closed or times out.
+    ///
+    /// This turns a menu into a picker or a wizard that produces a value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use serenity::{
+    /// #     builder::CreateMessage,
+    /// #     model::prelude::{Message, Reaction},
+    /// #     prelude::Context,
+    /// # };
+    /// use std::sync::Arc;
+    ///
+    /// use serenity_utils::menu::{Control, Menu, MenuOptions};
+    /// use serenity_utils::Error;
+    ///
+    /// async fn pick_page(menu: &mut Menu<'_>, _reaction: Reaction) {
+    ///     let page = menu.current_index();
+    ///     menu.set_result(page);
+    /// }
+    ///
+    /// async fn use_menu(ctx: &Context, msg: &Message) -> Result<(), Error> {
+    ///     let mut options = MenuOptions::default();
+    ///     options
+    ///         .controls
+    ///         .push(Control::new('✅'.into(), Arc::new(|m, r| Box::pin(pick_page(m, r)))));
+    ///
+    ///     let pages = vec!["Option one", "Option two"];
+    ///     let menu = Menu::new_from(ctx, msg, pages, options);
+    ///
+    ///     // The index of the page the user picked, if any.
+    ///     let picked: Option<usize> = menu.run_until().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// The errors are the same as [`run`].
+    ///
+    /// [`set_result`]: Menu::set_result
+    /// [`run`]: Menu::run
+    pub async fn run_until<T: Any + Send>(mut self) -> Result<Option<T>, Error> {
+        self.start().await?;
+
+        let reason = self.run_loop().await?;
+        self.end(reason).await;
+
+        Ok(self.result.take().and_then(|r| r.downcast::<T>().ok()).map(|r| *r))
+    }
+
+    /// Validates the options and prepares the menu to run.
+    async fn start(&mut self) -> MenuResult {
+        self.options.validate()?;
+
+        if self.options.delete_invocation {
+            // The menu works without the deletion, so errors, like missing
+            // permissions, are ignored.
+            if let Err(_e) = self.msg.delete(&self.ctx.http).await {
+                debug!(error = %_e, "failed to delete menu invocation");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the end callback, if there is one.
+    async fn end(&self, reason: MenuEndReason) {
+        if let Some(on_end) = &self.options.on_end {
+            on_end(reason, self.options.message.as_ref()).await;
+        }
+    }
+
+    async fn run_loop(&mut self) -> Result<MenuEndReason, Error> {
         loop {
             match self.work().await {
                 Ok((index, reaction)) => match self.options.controls.get(index) {
                     Some(control) => {
-                        Arc::clone(&control.function)(&mut self, reaction).await;
+                        Arc::clone(&control.function)(self, reaction).await;
+
+                        if self.closed {
+                            debug!("menu closed");
+                            return Ok(MenuEndReason::Closed);
+                        }
                     },
                     None => {
+                        debug!(index, "menu control not found");
+
                         // We don't have to return an error for this as bot won't
                         // have permission to remove reactions in all cases. This
                         // is simply an inconvenience for the user.
-                        let _ = self.clean_reactions().await;
-                        break;
+                        let _ = self.clean_up().await;
+                        return Ok(MenuEndReason::Closed);
                     },
                 },
+                Err(e) if e.discord_code() == Some(UNKNOWN_MESSAGE) => {
+                    // The message was deleted while the menu was running, so
+                    // there is nothing left to clean up.
+                    debug!("menu message deleted");
+                    self.options.message = None;
+
+                    return Ok(MenuEndReason::MessageDeleted);
+                },
                 Err(e) => {
-                    let _ = self.clean_reactions().await;
+                    let _ = self.clean_up().await;
 
                     // Timeout error isn't a valid error for the reaction menu.
                     if let Error::TimeoutError = e {
-                        break;
+                        debug!("menu timed out");
+
+                        if let Some(on_timeout) = self.options.on_timeout.clone() {
+                            on_timeout(self).await;
+                        }
+
+                        return Ok(MenuEndReason::TimedOut);
                     } else {
                         return Err(e);
                     }
                 },
             }
         }
-
-        Ok(self.options.message)
     }
 
     async fn work(&mut self) -> Result<(usize, Reaction), Error> {
-        if self.pages.is_empty() {
-            return Err(Error::from("`pages` is empty."));
+        if self.page_count() == 0 {
+            return Err(Error::msg("`pages` is empty."));
+        }
+
+        if self.options.page > self.page_count() - 1 {
+            return Err(Error::msg("`page` is out of bounds."));
         }
 
-        if self.options.page > self.pages.len() - 1 {
-            return Err(Error::from("`page` is out of bounds."));
+        debug!(page = self.options.page, "displaying menu page");
+
+        let index = self.options.page;
+        if let Some(provider) = &self.provider {
+            if let Entry::Vacant(entry) = self.rendered.entry(index) {
+                entry.insert(provider.page(index).await);
+            }
         }
 
-        let page = &self.pages[self.options.page];
+        let page = match self.provider {
+            Some(_) => &self.rendered[&index],
+            None => &self.pages[index],
+        };
+        let page_number = self.options.show_page_numbers.then(|| (index, self.page_count()));
+        let page = render_page(page, self.options.default_colour, page_number);
         match &mut self.options.message {
+            // The page is already displayed, so editing the message would only
+            // waste a request.
+            Some(_) if self.last_sent.as_ref() == Some(&page.0) => {
+                debug!("menu page unchanged, skipping edit");
+            },
             Some(m) => {
                 m.edit(&self.ctx.http, |m| {
                     m.0.clone_from(&page.0);
@@ -183,7 +599,7 @@ impl<'a> Menu<'a> {
                     .msg
                     .channel_id
                     .send_message(&self.ctx.http, |m| {
-                        m.clone_from(page);
+                        m.clone_from(&page);
 
                         m
                     })
@@ -195,12 +611,29 @@ impl<'a> Menu<'a> {
             },
         }
 
+        self.last_sent = Some(page.0.clone());
+
+        if self.options.consider_existing_reactions && !self.existing_checked {
+            self.existing_checked = true;
+
+            if let Some(found) = self.existing_reaction().await? {
+                return Ok(found);
+            }
+        }
+
         let message = self.options.message.as_ref().unwrap();
-        let mut reaction_collector = message
-            .await_reactions(&self.ctx)
+        let mut builder = message
+            .await_reactions(self.ctx)
             .timeout(Duration::from_secs_f64(self.options.timeout))
             .author_id(self.msg.author.id)
-            .build();
+            .removed(self.options.controls.iter().any(|c| c.on_removal));
+
+        if let Some(filter) = &self.options.reaction_filter {
+            let filter = Arc::clone(filter);
+            builder = builder.filter(move |r| filter(r));
+        }
+
+        let mut reaction_collector = builder.build();
 
         let (choice, reaction) = {
             let mut choice = None;
@@ -208,17 +641,29 @@ impl<'a> Menu<'a> {
             let mut found_one = false;
 
             while let Some(item) = reaction_collector.next().await {
-                if let ReactionAction::Added(r) = item.as_ref() {
-                    if !found_one {
-                        found_one = true;
-                    }
+                match item.as_ref() {
+                    ReactionAction::Added(r) => {
+                        if !found_one {
+                            found_one = true;
+                        }
 
-                    let r = r.as_ref().clone();
-                    if let Some(i) = self.process_reaction(&r) {
-                        choice = Some(i);
-                        reaction = Some(r);
-                        break;
-                    }
+                        let r = r.as_ref().clone();
+                        if let Some(i) = self.process_reaction(&r, false) {
+                            choice = Some(i);
+                            reaction = Some(r);
+                            break;
+                        }
+                    },
+                    // Removals are only of interest to controls triggered by
+                    // them, so other removals are ignored.
+                    ReactionAction::Removed(r) => {
+                        if let Some(i) = self.process_reaction(r, true) {
+                            found_one = true;
+                            choice = Some(i);
+                            reaction = Some(r.as_ref().clone());
+                            break;
+                        }
+                    },
                 }
             }
 
@@ -236,26 +681,101 @@ impl<'a> Menu<'a> {
     }
 
     async fn add_reactions(&self, msg: &Message) -> MenuResult {
-        if self.options.non_blocking {
-            let emojis = self.options.controls.iter().map(|c| c.emoji.clone()).collect::<Vec<_>>();
+        let emojis = self.options.controls.iter().map(|c| c.emoji.clone()).collect::<Vec<_>>();
+        let delay = self.options.reaction_delay.unwrap_or(DEFAULT_REACTION_DELAY);
 
-            add_reactions(self.ctx, msg, emojis).await?;
+        // An explicit delay forces the blocking path, so all controls are shown
+        // before the menu starts waiting for reactions.
+        if self.options.non_blocking && self.options.reaction_delay.is_none() {
+            add_reactions_with_delay(self.ctx, msg, emojis, delay).await?;
         } else {
-            // Using `add_reactions_blocking` requires extra iteration so we do
-            // it directly here.
-            for control in &self.options.controls {
-                self.ctx.http.create_reaction(msg.channel_id.0, msg.id.0, &control.emoji).await?;
+            match try_add_reactions_blocking_with_delay(self.ctx, msg, &emojis, delay).await {
+                // The menu is still usable if only some controls are missing.
+                Err(Error::FailedReactions(mut failures)) if failures.len() == emojis.len() => {
+                    return Err(failures.swap_remove(0).1.into());
+                },
+                Err(Error::FailedReactions(_)) | Ok(()) => (),
+                Err(e) => return Err(e),
             }
         }
 
         Ok(())
     }
 
-    fn process_reaction(&self, reaction: &Reaction) -> Option<usize> {
+    /// Returns the first control the user had already reacted with, if any.
+    async fn existing_reaction(&self) -> Result<Option<(usize, Reaction)>, Error> {
+        let message = match &self.options.message {
+            Some(m) => m,
+            None => return Ok(None),
+        };
+
+        for (idx, control) in self.options.controls.iter().enumerate() {
+            match self.user_reacted(message, &control.emoji).await {
+                Ok(true) => (),
+                Ok(false) => continue,
+                Err(_e) => {
+                    // Like missing control reactions, this shouldn't stop the
+                    // menu. The control can still be used by reacting again.
+                    debug!(emoji = %control.emoji, error = %_e, "failed to get reaction users");
+                    continue;
+                },
+            }
+
+            // `Reaction` can't be constructed directly, so it is deserialized.
+            let mut value = json!({
+                "channel_id": message.channel_id,
+                "message_id": message.id,
+                "user_id": self.msg.author.id,
+                "emoji": control.emoji,
+            });
+            if let Some(guild_id) = self.msg.guild_id {
+                value["guild_id"] = json!(guild_id);
+            }
+
+            let reaction: Reaction = from_value(value).map_err(|e| Error::msg(e.to_string()))?;
+
+            if self.options.reaction_filter.as_ref().is_some_and(|filter| !filter(&reaction)) {
+                continue;
+            }
+
+            debug!(control = idx, emoji = %control.emoji, "existing reaction matched menu control");
+            return Ok(Some((idx, reaction)));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns whether the menu's user reacted to `message` with `emoji`.
+    ///
+    /// Discord returns at most 100 users per request, so the users are fetched
+    /// page by page.
+    async fn user_reacted(&self, message: &Message, emoji: &ReactionType) -> Result<bool, Error> {
+        let mut after = None;
+
+        loop {
+            let users = message
+                .reaction_users(&self.ctx.http, emoji.clone(), Some(REACTION_USERS_LIMIT), after)
+                .await?;
+
+            if users.iter().any(|u| u.id == self.msg.author.id) {
+                return Ok(true);
+            }
+
+            match users.last() {
+                Some(last) if users.len() == usize::from(REACTION_USERS_LIMIT) => {
+                    after = Some(last.id);
+                },
+                _ => return Ok(false),
+            }
+        }
+    }
+
+    fn process_reaction(&self, reaction: &Reaction, removed: bool) -> Option<usize> {
         let emoji = &reaction.emoji;
 
         for (idx, control) in self.options.controls.iter().enumerate() {
-            if &control.emoji == emoji {
+            if (!removed || control.on_removal) && control.matches(emoji) {
+                debug!(control = idx, %emoji, "reaction matched menu control");
                 return Some(idx);
             }
         }
@@ -263,69 +783,605 @@ impl<'a> Menu<'a> {
         None
     }
 
-    async fn clean_reactions(&self) -> MenuResult {
+    /// Cleans up the menu's message according to [`MenuOptions::cleanup`].
+    async fn clean_up(&mut self) -> MenuResult {
+        let msg = match &self.options.message {
+            Some(msg) => msg,
+            None => return Ok(()),
+        };
+
+        match self.options.cleanup {
+            CleanupBehavior::DeleteMessage => {
+                msg.delete(&self.ctx.http).await?;
+                self.options.message = None;
+            },
+            CleanupBehavior::DeleteAllReactions => msg.delete_reactions(&self.ctx.http).await?,
+            CleanupBehavior::DeleteOwnReactions => {
+                for control in &self.options.controls {
+                    self.ctx
+                        .http
+                        .delete_reaction(msg.channel_id.0, msg.id.0, None, &control.emoji)
+                        .await?;
+                }
+            },
+            CleanupBehavior::Nothing => (),
+        }
+
+        Ok(())
+    }
+
+    async fn clean_control_reactions(&self) -> MenuResult {
         if let Some(msg) = &self.options.message {
-            msg.delete_reactions(&self.ctx.http).await?;
+            for control in &self.options.controls {
+                self.ctx
+                    .http
+                    .delete_message_reaction_emoji(msg.channel_id.0, msg.id.0, &control.emoji)
+                    .await?;
+            }
         }
 
         Ok(())
     }
-}
 
-/// Options to tweak a menu.
-///
-/// See [`Control`] for details to implement your own controls.
-pub struct MenuOptions {
-    /// The 0-indexed page number to start at.
+    /// Marks the menu as closed.
     ///
-    /// Defaults to `0`.
-    pub page: usize,
-    /// Number of seconds to keep the menu active.
+    /// The menu stops listening for reactions once the current control function
+    /// returns. The menu's message is left untouched.
+    pub fn close(&mut self) {
+        self.closed = true;
+    }
+
+    /// Sets the menu's result and closes the menu.
     ///
-    /// Defaults to `30.0`.
-    pub timeout: f64,
-    /// Optional message to edit.
+    /// The result is returned by [`run_until`] if it has the type
+    /// [`run_until`] expects. Setting a new result replaces the previous one.
     ///
-    /// If supplied, this message is edited instead of the bot creating a new
-    /// message to display the menu. This message must be sent by the bot.
+    /// [`run_until`]: Menu::run_until
+    pub fn set_result<T: Any + Send + Sync>(&mut self, value: T) {
+        self.result = Some(Box::new(value));
+        self.close();
+    }
+
+    /// Returns the message used to display the menu.
     ///
-    /// Defaults to `None`.
-    pub message: Option<Message>,
-    /// The controls for the menu.
+    /// This is `None` until the first page is sent, unless a message to edit
+    /// was supplied in [`MenuOptions`]. Inside a [`ControlFunction`], the
+    /// message is always available.
+    pub fn message(&self) -> Option<&Message> {
+        self.options.message.as_ref()
+    }
+
+    /// Returns the 0-indexed number of the page currently displayed.
+    pub fn current_index(&self) -> usize {
+        self.options.page
+    }
+
+    /// Moves the menu to the 0-indexed `page`.
     ///
-    /// Defaults to the following:
-    /// - ◀️ -> [`prev_page`]
-    /// - ❌ -> [`close_menu`]
-    /// - ▶️ -> [`next_page`]
+    /// The page is shown the next time the menu's message is updated, that is,
+    /// after the current [`ControlFunction`] returns. Returns `false` and keeps
+    /// the current page if `page` is out of bounds.
     ///
-    /// [`prev_page`]: prev_page()
-    /// [`close_menu`]: close_menu()
-    /// [`next_page`]: next_page()
-    pub controls: Vec<Control>,
-    /// Whether to add emojis in a separate task non-blocking task or not.
+    /// Control functions should use this instead of changing
+    /// [`MenuOptions::page`] directly, since an out of bounds page closes the
+    /// menu with an error.
+    pub fn set_page(&mut self, page: usize) -> bool {
+        if page >= self.page_count() {
+            return false;
+        }
+
+        self.options.page = page;
+
+        true
+    }
+
+    /// Returns the number of pages of the menu.
     ///
-    /// If set to `true`, addition of emojis doesn't stop the menu from working.
-    /// That is, if a reaction is added to the menu message and the user reacts
-    /// to it before other reactions are added, the bot will consider that
-    /// reaction and act appropriately.
+    /// This is the provider's length if the menu was created with
+    /// [`Menu::new_with_provider`].
+    pub fn page_count(&self) -> usize {
+        match &self.provider {
+            Some(provider) => provider.len(),
+            None => self.pages.len(),
+        }
+    }
+
+    /// Adds a control to the menu.
     ///
-    /// If set to `false`, no user reactions will be considered until the bot
-    /// adds all reactions.
+    /// If the menu's message has already been sent, the control's emoji is
+    /// also added as a reaction to it. The current user/bot requires the
+    /// `Add Reactions` permission for that.
     ///
-    /// Non-blocking addition is very slightly less efficient than blocking.
+    /// This can be used inside a [`ControlFunction`] to change the available
+    /// controls at runtime.
     ///
-    /// Defaults to `true`.
-    pub non_blocking: bool,
-}
+    /// ## Example
+    ///
+    /// ```
+    /// # use serenity::model::channel::Reaction;
+    /// use std::sync::Arc;
+    ///
+    /// use serenity_utils::menu::{close_menu, Control, ControlFunction, Menu};
+    ///
+    /// async fn show_close<'a>(menu: &mut Menu<'a>, reaction: Reaction) {
+    ///     let _ = reaction.delete(&menu.ctx.http).await;
+    ///
+    ///     let close = Control::new('❌'.into(), Arc::new(|m, r| Box::pin(close_menu(m, r))));
+    ///     let _ = menu.add_control(close).await;
+    /// }
+    ///
+    /// let control_function: ControlFunction = Arc::new(|m, r| Box::pin(show_close(m, r)));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::SerenityError`] if the reaction can't be added.
+    ///
+    /// [`Error::SerenityError`]: crate::error::Error::SerenityError
+    pub async fn add_control(&mut self, control: Control) -> MenuResult {
+        if let Some(msg) = &self.options.message {
+            self.ctx.http.create_reaction(msg.channel_id.0, msg.id.0, &control.emoji).await?;
+        }
 
-impl MenuOptions {
-    /// Creates a new [`MenuOptions`] object.
-    pub fn new(
-        page: usize,
-        timeout: f64,
-        message: Option<Message>,
-        controls: Vec<Control>,
-        non_blocking: bool,
+        self.options.controls.push(control);
+
+        Ok(())
+    }
+
+    /// Removes the control corresponding to `emoji` from the menu.
+    ///
+    /// If the menu's message has already been sent, the current user's/bot's
+    /// reaction for the emoji is also removed. Reactions of other users are
+    /// left as they are.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::SerenityError`] if the reaction can't be removed.
+    ///
+    /// [`Error::SerenityError`]: crate::error::Error::SerenityError
+    pub async fn remove_control(&mut self, emoji: &ReactionType) -> MenuResult {
+        let len = self.options.controls.len();
+        self.options.controls.retain(|c| !c.matches(emoji));
+
+        if self.options.controls.len() != len {
+            if let Some(msg) = &self.options.message {
+                self.ctx.http.delete_reaction(msg.channel_id.0, msg.id.0, None, emoji).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the menu's controls with `controls`.
+    ///
+    /// If the menu's message has already been sent, the current user's/bot's
+    /// reactions for removed controls are removed and reactions for new
+    /// controls are added after the existing ones. The current user/bot
+    /// requires the `Add Reactions` permission to add reactions.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::SerenityError`] if a reaction can't be added or
+    /// removed.
+    ///
+    /// [`Error::SerenityError`]: crate::error::Error::SerenityError
+    pub async fn set_controls(&mut self, controls: Vec<Control>) -> MenuResult {
+        let old = std::mem::replace(&mut self.options.controls, controls);
+
+        if let Some(msg) = &self.options.message {
+            let new = &self.options.controls;
+
+            for control in old.iter().filter(|o| !new.iter().any(|c| c.matches(&o.emoji))) {
+                self.ctx
+                    .http
+                    .delete_reaction(msg.channel_id.0, msg.id.0, None, &control.emoji)
+                    .await?;
+            }
+
+            for control in new.iter().filter(|c| !old.iter().any(|o| o.matches(&c.emoji))) {
+                self.ctx.http.create_reaction(msg.channel_id.0, msg.id.0, &control.emoji).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the page with the styling from the options applied.
+///
+/// `page_number` is the 0-indexed page and the number of pages, if the page
+/// number should be shown. The page is only cloned if it has to be changed.
+fn render_page<'p, 'a>(
+    page: &'p CreateMessage<'a>,
+    default_colour: Option<Colour>,
+    page_number: Option<(usize, usize)>,
+) -> Cow<'p, CreateMessage<'a>> {
+    let mut page = Cow::Borrowed(page);
+
+    if let Some(colour) = default_colour {
+        let has_uncoloured_embed = embeds(&page).iter().any(|e| e.get("color").is_none());
+
+        if has_uncoloured_embed {
+            if let Some(embeds) = page.to_mut().0.get_mut("embeds").and_then(Value::as_array_mut) {
+                for embed in embeds.iter_mut().filter_map(Value::as_object_mut) {
+                    embed.entry("color").or_insert_with(|| Value::from(colour.0));
+                }
+            }
+        }
+    }
+
+    if let Some((index, total)) = page_number {
+        let number = format!("Page {}/{}", index + 1, total);
+        let page = page.to_mut();
+
+        match page.0.get_mut("embeds").and_then(Value::as_array_mut).filter(|e| !e.is_empty()) {
+            Some(embeds) => {
+                for embed in embeds.iter_mut().filter_map(Value::as_object_mut) {
+                    let footer = embed.entry("footer").or_insert_with(|| json!({}));
+
+                    if let Some(footer) = footer.as_object_mut() {
+                        let text = match footer.get("text").and_then(Value::as_str) {
+                            Some(text) if !text.is_empty() => format!("{} • {}", text, number),
+                            _ => number.clone(),
+                        };
+                        footer.insert("text".to_string(), Value::from(text));
+                    }
+                }
+            },
+            None => {
+                let content = match page.0.get("content").and_then(Value::as_str) {
+                    Some(content) if !content.is_empty() => format!("{}\n\n{}", content, number),
+                    _ => number,
+                };
+                page.0.insert("content", Value::from(content));
+            },
+        }
+    }
+
+    page
+}
+
+/// Returns the embeds of a page as JSON objects.
+fn embeds<'b>(page: &'b CreateMessage<'_>) -> Vec<&'b serenity::json::JsonMap> {
+    page.0
+        .get("embeds")
+        .and_then(Value::as_array)
+        .map(|e| e.iter().filter_map(Value::as_object).collect())
+        .unwrap_or_default()
+}
+
+/// A trait for types that generate the pages of a menu on demand.
+///
+/// It is used by [`Menu::new_with_provider`] for menus whose pages are
+/// expensive to build, like pages fetched from an API.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{builder::CreateMessage, model::prelude::Message, prelude::Context};
+/// use serenity::async_trait;
+/// use serenity_utils::menu::{Menu, MenuOptions, PageProvider};
+/// use serenity_utils::Error;
+///
+/// struct Squares;
+///
+/// #[async_trait]
+/// impl<'a> PageProvider<'a> for Squares {
+///     async fn page(&self, index: usize) -> CreateMessage<'a> {
+///         let mut page = CreateMessage::default();
+///         page.content(format!("{} squared is {}.", index, index * index));
+///
+///         page
+///     }
+///
+///     fn len(&self) -> usize {
+///         100
+///     }
+/// }
+///
+/// async fn use_menu(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let menu = Menu::new_with_provider(ctx, msg, Squares, MenuOptions::default());
+///     let opt_message = menu.run().await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[async_trait]
+pub trait PageProvider<'a>: Send + Sync {
+    /// Generates the 0-indexed page `index`.
+    ///
+    /// `index` is always less than [`len`](PageProvider::len).
+    async fn page(&self, index: usize) -> CreateMessage<'a>;
+
+    /// Returns the number of pages.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no pages.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A trait for types that can be turned into a menu page.
+///
+/// It is used by [`Menu::new_from`]. Strings are used as the page's content,
+/// and embeds are used as the page's embed. A vector of embeds makes a page
+/// with all of them.
+pub trait IntoPage<'a> {
+    /// Converts the value into a page.
+    fn into_page(self) -> CreateMessage<'a>;
+}
+
+impl<'a> IntoPage<'a> for CreateMessage<'a> {
+    fn into_page(self) -> CreateMessage<'a> {
+        self
+    }
+}
+
+impl<'a> IntoPage<'a> for String {
+    fn into_page(self) -> CreateMessage<'a> {
+        let mut page = CreateMessage::default();
+        page.content(self);
+
+        page
+    }
+}
+
+impl<'a> IntoPage<'a> for &str {
+    fn into_page(self) -> CreateMessage<'a> {
+        let mut page = CreateMessage::default();
+        page.content(self);
+
+        page
+    }
+}
+
+impl<'a> IntoPage<'a> for CreateEmbed {
+    fn into_page(self) -> CreateMessage<'a> {
+        let mut page = CreateMessage::default();
+        page.set_embed(self);
+
+        page
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> IntoPage<'a> for crate::builder::embed::EmbedBuilder {
+    fn into_page(self) -> CreateMessage<'a> {
+        CreateEmbed::from(self).into_page()
+    }
+}
+
+/// A page with several embeds. Discord allows up to 10 embeds per message.
+impl<'a> IntoPage<'a> for Vec<CreateEmbed> {
+    fn into_page(self) -> CreateMessage<'a> {
+        let mut page = CreateMessage::default();
+        page.set_embeds(self);
+
+        page
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> IntoPage<'a> for crate::builder::message::MessageBuilder<'a> {
+    fn into_page(self) -> CreateMessage<'a> {
+        self.into()
+    }
+}
+
+/// Options to tweak a menu.
+///
+/// See [`Control`] for details to implement your own controls.
+///
+/// The options can be cloned to run the same menu layout many times. Cloning
+/// is cheap, as the control functions and the reaction filter are shared.
+/// The [`message`] field is cloned too, so set it to `None` on the clone if
+/// the menu should send a new message.
+///
+/// [`message`]: MenuOptions::message
+#[derive(Clone)]
+pub struct MenuOptions {
+    /// The 0-indexed page number to start at.
+    ///
+    /// While the menu runs, this is the page currently displayed. Control
+    /// functions should change it with [`Menu::set_page`] rather than directly.
+    ///
+    /// Defaults to `0`.
+    pub page: usize,
+    /// Number of seconds to keep the menu active.
+    ///
+    /// Use [`set_timeout`] to set it with a [`Duration`].
+    ///
+    /// Defaults to `30.0`.
+    ///
+    /// [`set_timeout`]: MenuOptions::set_timeout
+    pub timeout: f64,
+    /// Optional message to edit.
+    ///
+    /// If supplied, this message is edited instead of the bot creating a new
+    /// message to display the menu. This message must be sent by the bot.
+    ///
+    /// A supplied message is never deleted by [`close_menu`]. Only the control
+    /// reactions are removed from it when the menu is closed.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`close_menu`]: close_menu()
+    pub message: Option<Message>,
+    /// The controls for the menu.
+    ///
+    /// Defaults to the following:
+    /// - ◀️ -> [`prev_page`]
+    /// - ❌ -> [`close_menu`]
+    /// - ▶️ -> [`next_page`]
+    ///
+    /// If a control's reaction can't be added, for example because its emoji
+    /// is from a guild the current user/bot isn't in, the other controls are
+    /// still added. The menu only fails if none of the reactions can be added.
+    ///
+    /// [`prev_page`]: prev_page()
+    /// [`close_menu`]: close_menu()
+    /// [`next_page`]: next_page()
+    pub controls: Vec<Control>,
+    /// Whether to add emojis in a separate task non-blocking task or not.
+    ///
+    /// If set to `true`, addition of emojis doesn't stop the menu from working.
+    /// That is, if a reaction is added to the menu message and the user reacts
+    /// to it before other reactions are added, the bot will consider that
+    /// reaction and act appropriately.
+    ///
+    /// If set to `false`, no user reactions will be considered until the bot
+    /// adds all reactions.
+    ///
+    /// Non-blocking addition is very slightly less efficient than blocking.
+    /// It is ignored if [`reaction_delay`] is set.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`reaction_delay`]: MenuOptions::reaction_delay
+    pub non_blocking: bool,
+    /// Optional filter applied to reactions, in addition to the menu's checks.
+    ///
+    /// Only reactions for which the filter returns `true` are considered. This
+    /// can be used to, for example, require the user to have a certain role.
+    ///
+    /// Defaults to `None`.
+    pub reaction_filter: Option<ReactionFilter>,
+    /// Optional colour for the embeds of the pages.
+    ///
+    /// If set, it is used for every embed of a page that doesn't have its own
+    /// colour, including pages with several embeds. The pages themselves are
+    /// not modified.
+    ///
+    /// Defaults to `None`.
+    pub default_colour: Option<Colour>,
+    /// Whether to show the page number on each page, like "Page 3/7".
+    ///
+    /// The page number is added to the footer of each embed of the page,
+    /// after any existing footer text. Pages without embeds have it added at
+    /// the end of their content instead. The pages themselves are not
+    /// modified.
+    ///
+    /// Defaults to `false`.
+    pub show_page_numbers: bool,
+    /// Time to wait between adding each control's reaction.
+    ///
+    /// If set, the controls are always added in the blocking fashion, ignoring
+    /// [`non_blocking`], so they visibly appear one after another in their
+    /// order. A longer delay makes them appear from left to right.
+    ///
+    /// If `None`, the reactions are added as configured by [`non_blocking`]
+    /// with a delay of 250 milliseconds. Adding reactions back-to-back can hit
+    /// Discord's rate limit for reactions, which stalls the menu until it
+    /// resets. The small delay avoids these bursts, but it takes longer for
+    /// all controls to show up. Set it to `Some(Duration::ZERO)` to add
+    /// reactions without waiting.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`non_blocking`]: MenuOptions::non_blocking
+    pub reaction_delay: Option<Duration>,
+    /// Whether [`next_page`] and [`prev_page`] wrap around at the ends.
+    ///
+    /// If set to `true`, moving forward from the last page shows the first
+    /// page and vice versa. Otherwise, the menu stays on the first or last
+    /// page.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`next_page`]: next_page()
+    /// [`prev_page`]: prev_page()
+    pub wrap: bool,
+    /// Whether to consider reactions the user added before the menu started
+    /// listening.
+    ///
+    /// If set to `true`, the users of each control's reaction are fetched once
+    /// the menu's message is first displayed. If the user already reacted with
+    /// a control, that control is triggered before any new reactions are
+    /// awaited. This covers a user who reacts while the controls are being
+    /// added and reactions on a supplied [`message`].
+    ///
+    /// Checking requires one request per control, so it's only done once.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`message`]: MenuOptions::message
+    pub consider_existing_reactions: bool,
+    /// Whether the page controls, like [`next_page`] and [`prev_page`], add the
+    /// control's reaction back after removing the user's reaction.
+    ///
+    /// Some clients hide a control once the user's reaction is removed, so the
+    /// user can't navigate in the same direction again. Adding the reaction
+    /// back keeps the control usable, at the cost of an extra request.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`next_page`]: next_page()
+    /// [`prev_page`]: prev_page()
+    pub readd_reactions: bool,
+    /// Whether to delete the invocation message when the menu starts.
+    ///
+    /// This keeps channels clean by removing the command that opened the menu.
+    /// Deleting another user's message requires the `Manage Messages`
+    /// permission. The menu still runs if the message can't be deleted.
+    ///
+    /// Defaults to `false`.
+    pub delete_invocation: bool,
+    /// What to do with the menu's message when the menu times out or stops
+    /// because of an invalid choice or an error.
+    ///
+    /// Menus closed by a control function are cleaned up by the control
+    /// function instead, like [`close_menu`] does. If the message is deleted,
+    /// the menu returns `None` in place of the message.
+    ///
+    /// Deleting all reactions can be disruptive in busy channels, as it also
+    /// removes reactions other users added. Use
+    /// [`CleanupBehavior::DeleteOwnReactions`] to only remove the controls.
+    ///
+    /// Defaults to [`CleanupBehavior::DeleteAllReactions`].
+    ///
+    /// [`close_menu`]: close_menu()
+    pub cleanup: CleanupBehavior,
+    /// Optional callback run when the menu times out.
+    ///
+    /// It's run once, after the menu's message is cleaned up according to
+    /// [`cleanup`] and before [`on_end`]. This can be used to, for example,
+    /// edit the message to say the menu has expired.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`cleanup`]: MenuOptions::cleanup
+    /// [`on_end`]: MenuOptions::on_end
+    pub on_timeout: Option<TimeoutCallback>,
+    /// Optional callback run when the menu ends.
+    ///
+    /// It's run for every [`MenuEndReason`], right before the menu returns and
+    /// after the reactions or the message are cleaned up. It's not run if the
+    /// menu returns an error. This can be used to, for example, log the result
+    /// of a menu.
+    ///
+    /// Defaults to `None`.
+    pub on_end: Option<EndCallback>,
+}
+
+/// The maximum number of different reactions a message can have.
+const MAX_REACTIONS: usize = 20;
+
+/// The maximum number of users Discord returns for a reaction per request.
+const REACTION_USERS_LIMIT: u8 = 100;
+
+/// Delay between control reactions if [`MenuOptions::reaction_delay`] is not
+/// set.
+const DEFAULT_REACTION_DELAY: Duration = Duration::from_millis(250);
+
+impl MenuOptions {
+    /// Creates a new [`MenuOptions`] object.
+    pub fn new(
+        page: usize,
+        timeout: f64,
+        message: Option<Message>,
+        controls: Vec<Control>,
+        non_blocking: bool,
     ) -> Self {
         Self {
             page,
@@ -333,7 +1389,194 @@ impl MenuOptions {
             message,
             controls,
             non_blocking,
+            reaction_filter: None,
+            default_colour: None,
+            show_page_numbers: false,
+            reaction_delay: None,
+            wrap: true,
+            consider_existing_reactions: false,
+            readd_reactions: false,
+            delete_invocation: false,
+            cleanup: CleanupBehavior::default(),
+            on_timeout: None,
+            on_end: None,
+        }
+    }
+
+    /// Creates a [`MenuOptions`] object with controls to jump to the first and
+    /// last pages.
+    ///
+    /// The other options have their default values. The controls are:
+    /// - ⏪ -> [`first_page`]
+    /// - ◀️ -> [`prev_page`]
+    /// - ❌ -> [`close_menu`]
+    /// - ▶️ -> [`next_page`]
+    /// - ⏩ -> [`last_page`]
+    ///
+    /// [`first_page`]: first_page()
+    /// [`prev_page`]: prev_page()
+    /// [`close_menu`]: close_menu()
+    /// [`next_page`]: next_page()
+    /// [`last_page`]: last_page()
+    pub fn default_controls_with_ends() -> Self {
+        let mut options = Self::default();
+        options
+            .controls
+            .insert(0, Control::new('⏪'.into(), Arc::new(|m, r| Box::pin(first_page(m, r)))));
+        options
+            .controls
+            .push(Control::new('⏩'.into(), Arc::new(|m, r| Box::pin(last_page(m, r)))));
+
+        options
+    }
+
+    /// Updates the `page` field.
+    ///
+    /// This is the 0-indexed page number to start at. It defaults to `0`.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn page(&mut self, page: usize) -> &mut Self {
+        self.page = page;
+
+        self
+    }
+
+    /// Updates the `timeout` field.
+    ///
+    /// This is the number of seconds to keep the menu active. It defaults to
+    /// `30.0`. See [`set_timeout`] to use a [`Duration`].
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    ///
+    /// [`set_timeout`]: MenuOptions::set_timeout
+    pub fn timeout(&mut self, timeout: f64) -> &mut Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Updates the `message` field.
+    ///
+    /// This is the message to edit instead of sending a new one. It defaults
+    /// to `None`.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn message(&mut self, message: Option<Message>) -> &mut Self {
+        self.message = message;
+
+        self
+    }
+
+    /// Updates the `controls` field.
+    ///
+    /// This replaces all controls of the menu, including the default ones.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn controls(&mut self, controls: Vec<Control>) -> &mut Self {
+        self.controls = controls;
+
+        self
+    }
+
+    /// Adds a control after the existing controls.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn add_control(&mut self, control: Control) -> &mut Self {
+        self.controls.push(control);
+
+        self
+    }
+
+    /// Updates the `non_blocking` field.
+    ///
+    /// If `true`, the control reactions are added in a separate task. It
+    /// defaults to `true`.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn non_blocking(&mut self, non_blocking: bool) -> &mut Self {
+        self.non_blocking = non_blocking;
+
+        self
+    }
+
+    /// Sets the number of seconds to keep the menu active using a [`Duration`].
+    ///
+    /// The duration is converted and stored in the `timeout` field.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn timeout_duration(&mut self, timeout: Duration) -> &mut Self {
+        self.set_timeout(timeout)
+    }
+
+    /// Sets the time to keep the menu active.
+    ///
+    /// Unlike the `timeout` field, which is a number of seconds, this accepts
+    /// anything that can be converted into a [`Duration`]. The duration is
+    /// converted and stored in the `timeout` field.
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn set_timeout(&mut self, timeout: impl Into<Duration>) -> &mut Self {
+        self.timeout = timeout.into().as_secs_f64();
+
+        self
+    }
+
+    /// Removes controls whose emoji is already used by an earlier control.
+    ///
+    /// Only the first control for an emoji can ever be triggered, so the
+    /// others are removed. A menu with duplicate control emojis doesn't run,
+    /// see [`validate`].
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    ///
+    /// [`validate`]: MenuOptions::validate
+    pub fn dedupe_controls(&mut self) -> &mut Self {
+        let mut seen: Vec<ReactionType> = Vec::with_capacity(self.controls.len());
+
+        self.controls.retain(|control| {
+            if seen.iter().any(|emoji| control.matches(emoji)) {
+                false
+            } else {
+                seen.push(control.emoji.clone());
+                true
+            }
+        });
+
+        self
+    }
+
+    /// Checks whether the options can be used to run a menu.
+    ///
+    /// This is done automatically when a menu is run.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Other`] if there are more than 20 controls, as Discord
+    /// doesn't allow more than 20 different reactions on a message.
+    ///
+    /// Returns [`Error::Other`] if two controls have the same emoji, as only
+    /// the first of them could ever be triggered. Use [`dedupe_controls`] to
+    /// remove such controls.
+    ///
+    /// [`dedupe_controls`]: MenuOptions::dedupe_controls
+    /// [`Error::Other`]: crate::error::Error::Other
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> MenuResult {
+        if self.controls.len() > MAX_REACTIONS {
+            return Err(Error::msg(format!(
+                "A menu can't have more than {} controls, got {}.",
+                MAX_REACTIONS,
+                self.controls.len()
+            )));
         }
+
+        for (i, control) in self.controls.iter().enumerate() {
+            if self.controls[..i].iter().any(|c| c.matches(&control.emoji)) {
+                return Err(Error::msg(format!("duplicate control emoji: {}", control.emoji)));
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -351,20 +1594,113 @@ impl Default for MenuOptions {
             message: None,
             controls,
             non_blocking: true,
+            reaction_filter: None,
+            default_colour: None,
+            show_page_numbers: false,
+            reaction_delay: None,
+            wrap: true,
+            consider_existing_reactions: false,
+            readd_reactions: false,
+            delete_invocation: false,
+            cleanup: CleanupBehavior::default(),
+            on_timeout: None,
+            on_end: None,
         }
     }
 }
 
+/// A filter to decide whether a reaction should be considered by a menu.
+///
+/// See [`MenuOptions::reaction_filter`] for more details.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use serenity_utils::menu::ReactionFilter;
+///
+/// // Only consider reactions made in guilds.
+/// let filter: ReactionFilter = Arc::new(|r| r.guild_id.is_some());
+/// ```
+pub type ReactionFilter = Arc<dyn Fn(&Reaction) -> bool + Send + Sync>;
+
+/// A callback run when a menu ends.
+///
+/// It is given the reason the menu ended and the menu's message, if it still
+/// exists. See [`MenuOptions::on_end`] for more details.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use serenity_utils::menu::EndCallback;
+///
+/// let on_end: EndCallback = Arc::new(|reason, _message| {
+///     Box::pin(async move {
+///         println!("The menu ended: {:?}", reason);
+///     })
+/// });
+/// ```
+pub type EndCallback = Arc<
+    dyn for<'b> Fn(
+            MenuEndReason,
+            Option<&'b Message>,
+        ) -> Pin<Box<dyn Future<Output = ()> + 'b + Send>>
+        + Sync
+        + Send,
+>;
+
+/// A callback run when a menu times out.
+///
+/// It is given the menu, so it can use the menu's message and context. See
+/// [`MenuOptions::on_timeout`] for more details.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use serenity_utils::menu::TimeoutCallback;
+///
+/// let on_timeout: TimeoutCallback = Arc::new(|menu| {
+///     Box::pin(async move {
+///         let ctx = menu.ctx;
+///
+///         if let Some(message) = &mut menu.options.message {
+///             let _ = message.edit(&ctx.http, |m| m.content("This menu has expired.")).await;
+///         }
+///     })
+/// });
+/// ```
+pub type TimeoutCallback = Arc<
+    dyn for<'b> Fn(&'b mut Menu<'_>) -> Pin<Box<dyn Future<Output = ()> + 'b + Send>> + Sync + Send,
+>;
+
 /// A struct representing a control for reaction menus.
 ///
 /// Each control must have a unique emoji and a function to control it's
 /// behaviour. See [`ControlFunction`]'s documentation to learn more about how
 /// they are implemented.
+///
+/// Cloning a control is cheap, as the function is shared.
+#[derive(Clone)]
 pub struct Control {
     /// The emoji for the control.
     pub emoji: ReactionType,
     /// The [`ControlFunction`] to control the behaviour.
     pub function: ControlFunction,
+    /// Whether the control is also triggered when the user removes their
+    /// reaction.
+    ///
+    /// This allows "toggle" controls, like options of a settings menu that are
+    /// enabled by reacting and disabled by removing the reaction. The
+    /// function is called with the removed reaction, so it shouldn't try to
+    /// delete it. Removing a reaction never triggers other controls.
+    ///
+    /// Defaults to `false`.
+    pub on_removal: bool,
 }
 
 impl Control {
@@ -373,6 +1709,37 @@ impl Control {
         Self {
             emoji,
             function,
+            on_removal: false,
+        }
+    }
+
+    /// Creates a new [`Control`] object that is triggered both when the user
+    /// reacts and when they remove their reaction.
+    ///
+    /// See [`Control::on_removal`] for more details.
+    pub fn new_on_removal(emoji: ReactionType, function: ControlFunction) -> Self {
+        Self {
+            emoji,
+            function,
+            on_removal: true,
+        }
+    }
+
+    /// Checks whether `emoji` corresponds to the control's emoji.
+    ///
+    /// Custom emojis are compared by their IDs only, so an animated custom
+    /// emoji matches a reaction even if its `animated` flag or name differ.
+    pub fn matches(&self, emoji: &ReactionType) -> bool {
+        match (&self.emoji, emoji) {
+            (
+                ReactionType::Custom {
+                    id: a, ..
+                },
+                ReactionType::Custom {
+                    id: b, ..
+                },
+            ) => a == b,
+            (a, b) => a == b,
         }
     }
 }
@@ -389,8 +1756,8 @@ impl Control {
 ///     // Remove the reaction used to change the menu.
 ///     let _ = &reaction.delete(&menu.ctx.http).await;
 ///
-///     // Set page number to `0`.
-///     menu.options.page = 0;
+///     // Move to the page numbered `0`.
+///     menu.set_page(0);
 /// }
 /// ```
 ///
@@ -417,6 +1784,9 @@ pub type ControlFunction = Arc<
 
 /// Moves a reaction menu forward.
 ///
+/// On the last page, it moves to the first page if [`MenuOptions::wrap`] is
+/// `true`. Otherwise, the page isn't changed.
+///
 /// **Note:** This function is not a [`ControlFunction`]. To turn it into a
 /// control function, you must pin it and then create an `Arc` of it.
 ///
@@ -429,17 +1799,22 @@ pub type ControlFunction = Arc<
 ///
 /// `next_page_cfn` is a [`ControlFunction`] and can be used to control a menu.
 pub async fn next_page(menu: &mut Menu<'_>, reaction: Reaction) {
-    let _ = reaction.delete(&menu.ctx.http).await;
+    consume_reaction(menu, &reaction).await;
 
-    if menu.options.page == menu.pages.len() - 1 {
-        menu.options.page = 0;
+    if menu.options.page == menu.page_count() - 1 {
+        if menu.options.wrap {
+            menu.set_page(0);
+        }
     } else {
-        menu.options.page += 1;
+        menu.set_page(menu.options.page + 1);
     }
 }
 
 /// Moves a reaction menu backward.
 ///
+/// On the first page, it moves to the last page if [`MenuOptions::wrap`] is
+/// `true`. Otherwise, the page isn't changed.
+///
 /// **Note:** This function is not a [`ControlFunction`]. To turn it into a
 /// control function, you must pin it and then create an `Arc` of it.
 ///
@@ -452,17 +1827,130 @@ pub async fn next_page(menu: &mut Menu<'_>, reaction: Reaction) {
 ///
 /// `prev_page_cfn` is a [`ControlFunction`] and can be used to control a menu.
 pub async fn prev_page(menu: &mut Menu<'_>, reaction: Reaction) {
-    let _ = reaction.delete(&menu.ctx.http).await;
+    consume_reaction(menu, &reaction).await;
 
     if menu.options.page == 0 {
-        menu.options.page = menu.pages.len() - 1;
+        if menu.options.wrap {
+            menu.set_page(menu.page_count() - 1);
+        }
     } else {
-        menu.options.page -= 1;
+        menu.set_page(menu.options.page - 1);
+    }
+}
+
+/// Moves a reaction menu to the first page.
+///
+/// **Note:** This function is not a [`ControlFunction`]. To turn it into a
+/// control function, you must pin it and then create an `Arc` of it.
+///
+/// ```
+/// # use serenity_utils::menu::first_page;
+/// # use std::sync::Arc;
+/// #
+/// let first_page_cfn = Arc::new(|m, r| Box::pin(first_page(m, r)));
+/// ```
+///
+/// `first_page_cfn` is a [`ControlFunction`] and can be used to control a menu.
+pub async fn first_page(menu: &mut Menu<'_>, reaction: Reaction) {
+    consume_reaction(menu, &reaction).await;
+
+    menu.set_page(0);
+}
+
+/// Moves a reaction menu to the last page.
+///
+/// **Note:** This function is not a [`ControlFunction`]. To turn it into a
+/// control function, you must pin it and then create an `Arc` of it.
+///
+/// ```
+/// # use serenity_utils::menu::last_page;
+/// # use std::sync::Arc;
+/// #
+/// let last_page_cfn = Arc::new(|m, r| Box::pin(last_page(m, r)));
+/// ```
+///
+/// `last_page_cfn` is a [`ControlFunction`] and can be used to control a menu.
+pub async fn last_page(menu: &mut Menu<'_>, reaction: Reaction) {
+    consume_reaction(menu, &reaction).await;
+
+    menu.set_page(menu.page_count().saturating_sub(1));
+}
+
+/// Asks the user for a page number and moves a reaction menu to that page.
+///
+/// A message asking for the 1-indexed page number is sent in the menu's
+/// channel and the user's answer is awaited for as long as the menu's
+/// [`timeout`]. The question is deleted afterwards. If the answer isn't a
+/// number or is out of bounds, the page isn't changed.
+///
+/// This is useful for menus with many pages.
+///
+/// **Note:** This function is not a [`ControlFunction`]. To turn it into a
+/// control function, you must pin it and then create an `Arc` of it.
+///
+/// ```
+/// # use serenity_utils::menu::jump_to_page;
+/// # use std::sync::Arc;
+/// #
+/// let jump_to_page_cfn = Arc::new(|m, r| Box::pin(jump_to_page(m, r)));
+/// ```
+///
+/// `jump_to_page_cfn` is a [`ControlFunction`] and can be used to control a
+/// menu, for example with the 🔢 emoji.
+///
+/// [`timeout`]: MenuOptions::timeout
+pub async fn jump_to_page(menu: &mut Menu<'_>, reaction: Reaction) {
+    consume_reaction(menu, &reaction).await;
+
+    let question = format!("Which page do you want to go to? (1-{})", menu.page_count());
+    let prompt_msg = match menu.msg.channel_id.say(&menu.ctx.http, question).await {
+        Ok(m) => m,
+        Err(_e) => {
+            debug!(error = %_e, "failed to ask for menu page");
+            return;
+        },
+    };
+
+    let answer = message_prompt_content(
+        menu.ctx,
+        &prompt_msg,
+        &menu.msg.author,
+        menu.options.timeout as f32,
+    )
+    .await;
+
+    let _ = prompt_msg.delete(&menu.ctx.http).await;
+
+    // `set_page` ignores out of bounds pages.
+    if let Some(page) = answer.and_then(|a| a.trim().parse::<usize>().ok()) {
+        if let Some(page) = page.checked_sub(1) {
+            menu.set_page(page);
+        }
+    }
+}
+
+/// Removes the user's reaction so the control can be used again.
+///
+/// The control's reaction is added back if [`MenuOptions::readd_reactions`] is
+/// `true`.
+async fn consume_reaction(menu: &Menu<'_>, reaction: &Reaction) {
+    let _ = reaction.delete(&menu.ctx.http).await;
+
+    if menu.options.readd_reactions {
+        let _ = menu
+            .ctx
+            .http
+            .create_reaction(reaction.channel_id.0, reaction.message_id.0, &reaction.emoji)
+            .await;
     }
 }
 
 /// Closes a reaction menu by deleting the menu's message.
 ///
+/// If the menu was created with a message to edit (see [`MenuOptions::message`]),
+/// that message is not deleted. The control reactions are removed instead, like
+/// [`close_menu_keep`] does.
+///
 /// **Note:** This function is not a [`ControlFunction`]. To turn it into a
 /// control function, you must pin it and then create an `Arc` of it.
 ///
@@ -475,5 +1963,211 @@ pub async fn prev_page(menu: &mut Menu<'_>, reaction: Reaction) {
 ///
 /// `close_menu_cfn` is a [`ControlFunction`] and can be used to control a menu.
 pub async fn close_menu(menu: &mut Menu<'_>, _reaction: Reaction) {
-    let _ = menu.options.message.as_ref().unwrap().delete(&menu.ctx.http).await;
+    if menu.edits_message {
+        let _ = menu.clean_control_reactions().await;
+    } else if let Some(msg) = menu.message() {
+        let _ = msg.delete(&menu.ctx.http).await;
+    }
+
+    menu.close();
+}
+
+/// Closes a reaction menu by removing the menu's control reactions.
+///
+/// Unlike [`close_menu`], the menu's message is not deleted, so the last page
+/// displayed stays visible. The current user/bot requires the `Manage Messages`
+/// permission to remove the reactions. If it doesn't have the permission, the
+/// reactions are left as they are but the menu is still closed.
+///
+/// **Note:** This function is not a [`ControlFunction`]. To turn it into a
+/// control function, you must pin it and then create an `Arc` of it.
+///
+/// ```
+/// # use serenity_utils::menu::close_menu_keep;
+/// # use std::sync::Arc;
+/// #
+/// let close_menu_keep_cfn = Arc::new(|m, r| Box::pin(close_menu_keep(m, r)));
+/// ```
+///
+/// `close_menu_keep_cfn` is a [`ControlFunction`] and can be used to control a menu.
+pub async fn close_menu_keep(menu: &mut Menu<'_>, _reaction: Reaction) {
+    let _ = menu.clean_control_reactions().await;
+
+    menu.close();
+}
+
+/// Number emojis used by [`select_menu`] to select an item on a page.
+const NUMBER_EMOJIS: [&str; 10] = [
+    "1\u{fe0f}\u{20e3}",
+    "2\u{fe0f}\u{20e3}",
+    "3\u{fe0f}\u{20e3}",
+    "4\u{fe0f}\u{20e3}",
+    "5\u{fe0f}\u{20e3}",
+    "6\u{fe0f}\u{20e3}",
+    "7\u{fe0f}\u{20e3}",
+    "8\u{fe0f}\u{20e3}",
+    "9\u{fe0f}\u{20e3}",
+    "\u{1f51f}",
+];
+
+/// Displays a paginated list of items and lets the user select one of them.
+///
+/// Each page lists up to `per_page` items, numbered with 1️⃣ to 🔟. The user
+/// reacts with a number emoji to select the item on the current page. ◀️ and ▶️
+/// are added to move between pages if there is more than one page, and ❌
+/// closes the menu.
+///
+/// It returns the index of the selected item in `items`. `None` is returned if
+/// the menu is closed or if the user doesn't select an item within `timeout`
+/// seconds.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::Message, prelude::Context};
+/// use serenity_utils::menu::select_menu;
+/// use serenity_utils::Error;
+///
+/// async fn pick_fruit(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let fruits = ["Apple", "Banana", "Cherry", "Date", "Elderberry", "Fig"];
+///
+///     if let Some(index) = select_menu(ctx, msg, &fruits, 5, 30.0).await? {
+///         // `fruits[index]` was selected.
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::Other`] if
+/// - `items` is empty
+/// - `per_page` is `0` or greater than `10`
+///
+/// It can also return the same errors as [`Menu::run`].
+///
+/// [`Error::Other`]: crate::error::Error::Other
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(channel_id = %msg.channel_id))
+)]
+pub async fn select_menu<T: Display>(
+    ctx: &Context,
+    msg: &Message,
+    items: &[T],
+    per_page: usize,
+    timeout: f64,
+) -> Result<Option<usize>, Error> {
+    if per_page == 0 || per_page > NUMBER_EMOJIS.len() {
+        return Err(Error::msg("`per_page` must be between 1 and 10."));
+    }
+
+    let total = items.len().div_ceil(per_page);
+    let pages = items
+        .chunks(per_page)
+        .enumerate()
+        .map(|(page, chunk)| {
+            let mut content = String::new();
+            for (emoji, item) in NUMBER_EMOJIS.iter().zip(chunk) {
+                let _ = writeln!(content, "{} {}", emoji, item);
+            }
+            let _ = write!(content, "\nPage {}/{}", page + 1, total);
+
+            let mut message = CreateMessage::default();
+            message.content(content);
+
+            message
+        })
+        .collect::<Vec<_>>();
+
+    let mut controls = Vec::new();
+    if pages.len() > 1 {
+        controls.push(Control::new('◀'.into(), Arc::new(|m, r| Box::pin(prev_page(m, r)))));
+    }
+    controls.push(Control::new('❌'.into(), Arc::new(|m, r| Box::pin(close_menu(m, r)))));
+    if pages.len() > 1 {
+        controls.push(Control::new('▶'.into(), Arc::new(|m, r| Box::pin(next_page(m, r)))));
+    }
+
+    let len = items.len();
+    for (n, emoji) in NUMBER_EMOJIS.iter().enumerate().take(per_page.min(len)) {
+        controls.push(Control::new(
+            ReactionType::Unicode(emoji.to_string()),
+            Arc::new(move |m, r| Box::pin(select_item(m, r, n, per_page, len))),
+        ));
+    }
+
+    let options = MenuOptions {
+        timeout,
+        controls,
+        ..Default::default()
+    };
+
+    let selection = Menu::new(ctx, msg, &pages, options).run_until::<usize>().await?;
+
+    debug!(?selection, "select menu finished");
+
+    Ok(selection)
+}
+
+async fn select_item(
+    menu: &mut Menu<'_>,
+    reaction: Reaction,
+    n: usize,
+    per_page: usize,
+    len: usize,
+) {
+    let index = menu.options.page * per_page + n;
+
+    // The last page may have fewer items than there are number controls.
+    if index >= len {
+        let _ = reaction.delete(&menu.ctx.http).await;
+        return;
+    }
+
+    // Removing the reactions is only cosmetic, the selection stands anyway.
+    let _ = menu.clean_up().await;
+    menu.set_result(index);
+}
+
+/// Splits `text` into pages with [`pagify`] and shows them in a menu.
+///
+/// This is a shortcut for running a menu created with [`Menu::from_text`].
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::Message, prelude::Context};
+/// use serenity_utils::formatting::PagifyOptions;
+/// use serenity_utils::menu::{paginated_message, MenuOptions};
+/// use serenity_utils::Error;
+///
+/// async fn show_logs(ctx: &Context, msg: &Message, logs: &str) -> Result<(), Error> {
+///     let mut pagify_options = PagifyOptions::new();
+///     pagify_options.page_length(1000);
+///
+///     paginated_message(ctx, msg, logs, Some("log"), pagify_options, MenuOptions::default())
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::Other`] if `text` is empty or only whitespace. Other
+/// errors are the same as [`Menu::run`].
+///
+/// [`pagify`]: crate::formatting::pagify
+/// [`Error::Other`]: crate::error::Error::Other
+pub async fn paginated_message(
+    ctx: &Context,
+    msg: &Message,
+    text: &str,
+    code_block: Option<&str>,
+    pagify_options: PagifyOptions<'_>,
+    options: MenuOptions,
+) -> Result<Option<Message>, Error> {
+    Menu::from_text(ctx, msg, text, code_block, pagify_options, options).run().await
 }
@@ -0,0 +1,649 @@
+//! Component-based menu functionality.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serenity::builder::{CreateButton, CreateComponents, CreateMessage};
+use serenity::json::Value;
+use serenity::model::interactions::message_component::{ButtonStyle, MessageComponentInteraction};
+use serenity::model::prelude::{Message, ReactionType};
+use serenity::prelude::Context;
+use serenity::utils::Colour;
+
+use super::{render_page, IntoPage, MenuEndReason, MenuResult, UNKNOWN_MESSAGE};
+use crate::Error;
+
+/// The maximum number of buttons in an action row.
+const BUTTONS_PER_ROW: usize = 5;
+
+/// The maximum number of buttons a message can have.
+const MAX_BUTTONS: usize = 25;
+
+/// The maximum length of a component's custom ID.
+const MAX_CUSTOM_ID_LENGTH: usize = 100;
+
+/// A menu that uses buttons instead of reactions for its controls.
+///
+/// It works like [`Menu`], but each control is shown as a button below the
+/// page and the user clicks it instead of reacting. Unlike reactions, buttons
+/// don't need to be cleaned up, so the current user/bot doesn't require the
+/// `Manage Messages` permission. When the menu stops, its buttons are
+/// disabled, unless a control removed them.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::Message, prelude::Context};
+/// use serenity_utils::menu::{ButtonMenu, ButtonMenuOptions};
+/// use serenity_utils::Error;
+///
+/// async fn use_menu(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let pages = vec!["Page number one!", "Page number two!"];
+///
+///     let menu = ButtonMenu::new_from(ctx, msg, pages, ButtonMenuOptions::default());
+///     let opt_message = menu.run().await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Menu`]: super::Menu
+pub struct ButtonMenu<'a> {
+    /// The Discord/serenity context.
+    pub ctx: &'a Context,
+    /// The invocation message.
+    pub msg: &'a Message,
+    /// The pages of the menu.
+    ///
+    /// The buttons of the menu are added to each page when it is displayed,
+    /// so pages shouldn't have components of their own.
+    pub pages: Cow<'a, [CreateMessage<'a>]>,
+    /// The menu options.
+    pub options: ButtonMenuOptions,
+    closed: bool,
+    edits_message: bool,
+    last_sent: Option<HashMap<&'static str, Value>>,
+    buttons_shown: bool,
+}
+
+impl<'a> ButtonMenu<'a> {
+    /// Creates a new [`ButtonMenu`] object.
+    pub fn new(
+        ctx: &'a Context,
+        msg: &'a Message,
+        pages: &'a [CreateMessage<'a>],
+        options: ButtonMenuOptions,
+    ) -> Self {
+        Self::with_pages(ctx, msg, Cow::Borrowed(pages), options)
+    }
+
+    /// Creates a new [`ButtonMenu`] object from anything that can be turned
+    /// into pages.
+    ///
+    /// Each item of `pages` is converted into a page with [`IntoPage`].
+    pub fn new_from<P: IntoPage<'a>>(
+        ctx: &'a Context,
+        msg: &'a Message,
+        pages: Vec<P>,
+        options: ButtonMenuOptions,
+    ) -> Self {
+        let pages = pages.into_iter().map(IntoPage::into_page).collect::<Vec<_>>();
+
+        Self::with_pages(ctx, msg, Cow::Owned(pages), options)
+    }
+
+    fn with_pages(
+        ctx: &'a Context,
+        msg: &'a Message,
+        pages: Cow<'a, [CreateMessage<'a>]>,
+        options: ButtonMenuOptions,
+    ) -> Self {
+        let edits_message = options.message.is_some();
+
+        Self {
+            ctx,
+            msg,
+            pages,
+            options,
+            closed: false,
+            edits_message,
+            last_sent: None,
+            buttons_shown: false,
+        }
+    }
+
+    /// Runs the button menu.
+    ///
+    /// It returns the message used to display the menu after running. If the
+    /// message is deleted while the menu is running, the menu stops and `None`
+    /// is returned. See [`run_with_reason`] to know why the menu stopped.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::SerenityError`] if
+    /// - `msg` is specified in [`ButtonMenuOptions`] but the current user/bot
+    ///   isn't the author of the message
+    /// - the message content lengths are over Discord's limit
+    /// - current user/bot doesn't have the permissions to send an message/embed
+    ///
+    /// Returns [`Error::Other`] if
+    /// - `pages` is empty
+    /// - the page number specified in [`ButtonMenuOptions`] is out of bounds
+    /// - the options are invalid, see [`ButtonMenuOptions::validate`]
+    ///
+    /// [`Error::SerenityError`]: crate::error::Error::SerenityError
+    /// [`Error::Other`]: crate::error::Error::Other
+    /// [`run_with_reason`]: ButtonMenu::run_with_reason
+    pub async fn run(self) -> Result<Option<Message>, Error> {
+        self.run_with_reason().await.map(|(message, _)| message)
+    }
+
+    /// Runs the button menu and returns why it stopped.
+    ///
+    /// It returns the message used to display the menu along with the
+    /// [`MenuEndReason`]. If the menu's message is deleted while the menu is
+    /// running, the menu stops without an error and `None` is returned in place
+    /// of the message.
+    ///
+    /// ## Errors
+    ///
+    /// The errors are the same as [`run`].
+    ///
+    /// [`run`]: ButtonMenu::run
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(channel_id = %self.msg.channel_id))
+    )]
+    pub async fn run_with_reason(mut self) -> Result<(Option<Message>, MenuEndReason), Error> {
+        self.options.validate()?;
+
+        let result = self.run_loop().await;
+
+        // Buttons left enabled would look usable after the menu has stopped.
+        // The menu has ended either way, so a failure to disable them is
+        // ignored.
+        if self.buttons_shown {
+            let _ = self.disable_buttons().await;
+        }
+
+        Ok((self.options.message, result?))
+    }
+
+    async fn run_loop(&mut self) -> Result<MenuEndReason, Error> {
+        loop {
+            match self.work().await {
+                Ok((index, interaction)) => {
+                    // The message is updated by the menu itself, so the click
+                    // only has to be acknowledged.
+                    if let Err(_e) = interaction.defer(&self.ctx.http).await {
+                        debug!(error = %_e, "failed to acknowledge menu button");
+                    }
+
+                    let control = &self.options.controls[index];
+                    Arc::clone(&control.function)(self, interaction).await;
+
+                    if self.closed {
+                        debug!("button menu closed");
+                        return Ok(MenuEndReason::Closed);
+                    }
+                },
+                Err(e) if e.discord_code() == Some(UNKNOWN_MESSAGE) => {
+                    debug!("button menu message deleted");
+                    self.options.message = None;
+
+                    return Ok(MenuEndReason::MessageDeleted);
+                },
+                Err(Error::TimeoutError) => {
+                    debug!("button menu timed out");
+                    return Ok(MenuEndReason::TimedOut);
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn work(&mut self) -> Result<(usize, Arc<MessageComponentInteraction>), Error> {
+        if self.pages.is_empty() {
+            return Err(Error::msg("`pages` is empty."));
+        }
+
+        if self.options.page > self.pages.len() - 1 {
+            return Err(Error::msg("`page` is out of bounds."));
+        }
+
+        debug!(page = self.options.page, "displaying button menu page");
+
+        let mut page =
+            render_page(&self.pages[self.options.page], self.options.default_colour, None)
+                .into_owned();
+        page.set_components(self.components(false));
+
+        match &mut self.options.message {
+            // The page is already displayed, so editing the message would only
+            // waste a request.
+            Some(_) if self.last_sent.as_ref() == Some(&page.0) => {
+                debug!("button menu page unchanged, skipping edit");
+            },
+            Some(m) => {
+                m.edit(&self.ctx.http, |m| {
+                    m.0.clone_from(&page.0);
+
+                    m
+                })
+                .await?;
+            },
+            None => {
+                let msg = self
+                    .msg
+                    .channel_id
+                    .send_message(&self.ctx.http, |m| {
+                        m.clone_from(&page);
+
+                        m
+                    })
+                    .await?;
+
+                self.options.message = Some(msg);
+            },
+        }
+
+        self.last_sent = Some(page.0.clone());
+        self.buttons_shown = true;
+
+        let message = self.options.message.as_ref().unwrap();
+        // Ignored clicks must not restart the timeout, or they could keep the
+        // menu alive forever.
+        let deadline = Instant::now() + Duration::from_secs_f64(self.options.timeout);
+
+        loop {
+            let interaction = message
+                .await_component_interaction(self.ctx)
+                .author_id(self.msg.author.id)
+                .timeout(deadline.saturating_duration_since(Instant::now()))
+                .await
+                .ok_or(Error::TimeoutError)?;
+
+            let custom_id = &interaction.data.custom_id;
+            match self.options.controls.iter().position(|c| &c.custom_id == custom_id) {
+                Some(index) => {
+                    debug!(control = index, %custom_id, "button matched menu control");
+                    return Ok((index, interaction));
+                },
+                None => {
+                    // Buttons of a previous set of controls may still be shown
+                    // on the user's client.
+                    debug!(%custom_id, "button menu control not found");
+                    let _ = interaction.defer(&self.ctx.http).await;
+                },
+            }
+        }
+    }
+
+    /// Returns the action rows with a button for each control.
+    fn components(&self, disabled: bool) -> CreateComponents {
+        let mut components = CreateComponents::default();
+
+        for row in self.options.controls.chunks(BUTTONS_PER_ROW) {
+            components.create_action_row(|r| {
+                for control in row {
+                    r.add_button(control.button(disabled));
+                }
+
+                r
+            });
+        }
+
+        components
+    }
+
+    async fn disable_buttons(&mut self) -> MenuResult {
+        let components = self.components(true);
+
+        if let Some(msg) = &mut self.options.message {
+            msg.edit(&self.ctx.http, |m| m.set_components(components)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn remove_buttons(&mut self) -> MenuResult {
+        if let Some(msg) = &mut self.options.message {
+            msg.edit(&self.ctx.http, |m| m.set_components(CreateComponents::default())).await?;
+            self.buttons_shown = false;
+        }
+
+        Ok(())
+    }
+
+    /// Marks the menu as closed.
+    ///
+    /// The menu stops listening for button clicks once the current control
+    /// function returns. The menu's buttons are disabled, but the message is
+    /// otherwise left untouched.
+    pub fn close(&mut self) {
+        self.closed = true;
+    }
+
+    /// Returns the message used to display the menu.
+    ///
+    /// This is `None` until the first page is sent, unless a message to edit
+    /// was supplied in [`ButtonMenuOptions`]. Inside a
+    /// [`ButtonControlFunction`], the message is always available.
+    pub fn message(&self) -> Option<&Message> {
+        self.options.message.as_ref()
+    }
+
+    /// Returns the 0-indexed number of the page currently displayed.
+    pub fn current_index(&self) -> usize {
+        self.options.page
+    }
+
+    /// Moves the menu to the 0-indexed `page`.
+    ///
+    /// The page is shown after the current [`ButtonControlFunction`] returns.
+    /// Returns `false` and keeps the current page if `page` is out of bounds.
+    pub fn set_page(&mut self, page: usize) -> bool {
+        if page >= self.pages.len() {
+            return false;
+        }
+
+        self.options.page = page;
+
+        true
+    }
+}
+
+/// Options to tweak a [`ButtonMenu`].
+///
+/// See [`ButtonControl`] for details to implement your own controls.
+#[derive(Clone)]
+pub struct ButtonMenuOptions {
+    /// The 0-indexed page number to start at.
+    ///
+    /// While the menu runs, this is the page currently displayed. Control
+    /// functions should change it with [`ButtonMenu::set_page`] rather than
+    /// directly.
+    ///
+    /// Defaults to `0`.
+    pub page: usize,
+    /// Number of seconds to keep the menu active.
+    ///
+    /// Defaults to `30.0`.
+    pub timeout: f64,
+    /// Optional message to edit.
+    ///
+    /// If supplied, this message is edited instead of the bot creating a new
+    /// message to display the menu. This message must be sent by the bot.
+    ///
+    /// A supplied message is never deleted by [`ButtonControl::close`]. Only
+    /// the buttons are removed from it when the menu is closed.
+    ///
+    /// Defaults to `None`.
+    pub message: Option<Message>,
+    /// The controls for the menu.
+    ///
+    /// The buttons are shown in rows of five, in the order of the controls.
+    ///
+    /// Defaults to [`ButtonControl::prev_page`], [`ButtonControl::close`] and
+    /// [`ButtonControl::next_page`].
+    pub controls: Vec<ButtonControl>,
+    /// Optional colour for the embeds of the pages.
+    ///
+    /// See [`MenuOptions::default_colour`] for more details.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`MenuOptions::default_colour`]: super::MenuOptions::default_colour
+    pub default_colour: Option<Colour>,
+    /// Whether the next and previous page controls wrap around at the ends.
+    ///
+    /// Defaults to `true`.
+    pub wrap: bool,
+}
+
+impl ButtonMenuOptions {
+    /// Sets the number of seconds to keep the menu active using a [`Duration`].
+    ///
+    /// It returns a mutable reference to the struct for easy chaining.
+    pub fn timeout_duration(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout.as_secs_f64();
+
+        self
+    }
+
+    /// Checks whether the options can be used to run a menu.
+    ///
+    /// This is done automatically when a menu is run.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Other`] if
+    /// - there are more than 25 controls, the maximum number of buttons a
+    ///   message can have
+    /// - a control's custom ID is empty or longer than 100 characters
+    /// - two controls have the same custom ID
+    ///
+    /// [`Error::Other`]: crate::error::Error::Other
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> MenuResult {
+        if self.controls.len() > MAX_BUTTONS {
+            return Err(Error::msg(format!(
+                "A button menu can't have more than {} controls, got {}.",
+                MAX_BUTTONS,
+                self.controls.len()
+            )));
+        }
+
+        for (i, control) in self.controls.iter().enumerate() {
+            let len = control.custom_id.chars().count();
+            if len == 0 || len > MAX_CUSTOM_ID_LENGTH {
+                return Err(Error::msg(format!(
+                    "custom ID must be between 1 and {} characters: {:?}",
+                    MAX_CUSTOM_ID_LENGTH, control.custom_id
+                )));
+            }
+
+            if self.controls[..i].iter().any(|c| c.custom_id == control.custom_id) {
+                return Err(Error::msg(format!(
+                    "duplicate control custom ID: {}",
+                    control.custom_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ButtonMenuOptions {
+    fn default() -> Self {
+        Self {
+            page: 0,
+            timeout: 30.0,
+            message: None,
+            controls: vec![
+                ButtonControl::prev_page(),
+                ButtonControl::close(),
+                ButtonControl::next_page(),
+            ],
+            default_colour: None,
+            wrap: true,
+        }
+    }
+}
+
+/// A control for button menus.
+///
+/// Each control is shown as a button and must have a unique custom ID. The
+/// click is acknowledged before the control's function is called.
+///
+/// Cloning a control is cheap, as the function is shared.
+#[derive(Clone)]
+pub struct ButtonControl {
+    /// The custom ID of the control's button.
+    pub custom_id: String,
+    /// The emoji of the control's button.
+    pub emoji: Option<ReactionType>,
+    /// The label of the control's button.
+    pub label: Option<String>,
+    /// The style of the control's button.
+    pub style: ButtonStyle,
+    /// The [`ButtonControlFunction`] to control the behaviour.
+    pub function: ButtonControlFunction,
+}
+
+impl ButtonControl {
+    /// Creates a new [`ButtonControl`] object with a secondary button showing
+    /// `emoji`.
+    pub fn new(
+        custom_id: impl Into<String>,
+        emoji: ReactionType,
+        function: ButtonControlFunction,
+    ) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            emoji: Some(emoji),
+            label: None,
+            style: ButtonStyle::Secondary,
+            function,
+        }
+    }
+
+    /// Creates a control that moves the menu forward.
+    ///
+    /// On the last page, it moves to the first page if
+    /// [`ButtonMenuOptions::wrap`] is `true`.
+    pub fn next_page() -> Self {
+        Self::new("serenity_utils_menu_next", '▶'.into(), Arc::new(|m, _| Box::pin(next(m))))
+    }
+
+    /// Creates a control that moves the menu backward.
+    ///
+    /// On the first page, it moves to the last page if
+    /// [`ButtonMenuOptions::wrap`] is `true`.
+    pub fn prev_page() -> Self {
+        Self::new("serenity_utils_menu_prev", '◀'.into(), Arc::new(|m, _| Box::pin(prev(m))))
+    }
+
+    /// Creates a control that moves the menu to the first page.
+    pub fn first_page() -> Self {
+        Self::new(
+            "serenity_utils_menu_first",
+            '⏪'.into(),
+            Arc::new(|m, _| {
+                Box::pin(async move {
+                    m.set_page(0);
+                })
+            }),
+        )
+    }
+
+    /// Creates a control that moves the menu to the last page.
+    pub fn last_page() -> Self {
+        Self::new(
+            "serenity_utils_menu_last",
+            '⏩'.into(),
+            Arc::new(|m, _| {
+                Box::pin(async move {
+                    m.set_page(m.pages.len().saturating_sub(1));
+                })
+            }),
+        )
+    }
+
+    /// Creates a control that closes the menu by deleting its message.
+    ///
+    /// If the menu was created with a message to edit, that message is not
+    /// deleted. Its buttons are removed instead.
+    pub fn close() -> Self {
+        let mut control = Self::new(
+            "serenity_utils_menu_close",
+            '❌'.into(),
+            Arc::new(|m, _| Box::pin(close(m))),
+        );
+        control.style = ButtonStyle::Danger;
+
+        control
+    }
+
+    /// Returns the button for the control.
+    fn button(&self, disabled: bool) -> CreateButton {
+        let mut button = CreateButton::default();
+        button.custom_id(&self.custom_id).style(self.style).disabled(disabled);
+
+        if let Some(emoji) = &self.emoji {
+            button.emoji(emoji.clone());
+        }
+
+        if let Some(label) = &self.label {
+            button.label(label);
+        }
+
+        button
+    }
+}
+
+/// A function used to control the behaviour of a button menu's button.
+///
+/// It is given the menu and the interaction of the click, which has already
+/// been acknowledged.
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use serenity_utils::menu::{ButtonControl, ButtonControlFunction};
+///
+/// let first_page: ButtonControlFunction = Arc::new(|menu, _interaction| {
+///     Box::pin(async move {
+///         menu.set_page(0);
+///     })
+/// });
+///
+/// let control = ButtonControl::new("first", '⏪'.into(), first_page);
+/// ```
+pub type ButtonControlFunction = Arc<
+    dyn for<'b> Fn(
+            &'b mut ButtonMenu<'_>,
+            Arc<MessageComponentInteraction>,
+        ) -> Pin<Box<dyn Future<Output = ()> + 'b + Send>>
+        + Sync
+        + Send,
+>;
+
+async fn next(menu: &mut ButtonMenu<'_>) {
+    if menu.options.page == menu.pages.len() - 1 {
+        if menu.options.wrap {
+            menu.set_page(0);
+        }
+    } else {
+        menu.set_page(menu.options.page + 1);
+    }
+}
+
+async fn prev(menu: &mut ButtonMenu<'_>) {
+    if menu.options.page == 0 {
+        if menu.options.wrap {
+            menu.set_page(menu.pages.len() - 1);
+        }
+    } else {
+        menu.set_page(menu.options.page - 1);
+    }
+}
+
+async fn close(menu: &mut ButtonMenu<'_>) {
+    if menu.edits_message {
+        let _ = menu.remove_buttons().await;
+    } else if let Some(msg) = menu.message() {
+        if msg.delete(&menu.ctx.http).await.is_ok() {
+            menu.buttons_shown = false;
+        }
+    }
+
+    menu.close();
+}
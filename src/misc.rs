@@ -1,32 +1,64 @@
 //! Miscellaneous utility functions to aid with performing common tasks.
 
-use serenity::model::prelude::{Message, ReactionType};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serenity::model::prelude::{ChannelId, Message, MessageId, Permissions, ReactionType};
 use serenity::prelude::Context;
+use serenity::utils::Colour;
 use serenity::Error;
 
 /// Adds reactions in a non-blocking fashion.
 ///
 /// This allows you to perform other tasks while reactions are being added. This
 /// works by creating a separate task for adding emojis in the background. The
-/// order of `emojis` is preserved.
+/// order of `emojis` is preserved. If a reaction can't be added, for example
+/// because it's a custom emoji the current user/bot can't use, the remaining
+/// reactions are still added.
+///
+/// Since the reactions are added in the background, failures can't be reported
+/// and this function never returns an error. Use [`try_add_reactions_blocking`]
+/// to find out which reactions couldn't be added.
 ///
 /// See [`add_reactions_blocking`] to add reactions in a blocking fashion. This
 /// function is slightly less efficient than the blocking counterpart.
+///
+/// See [`add_reactions_with_delay`] to wait between adding reactions.
 pub async fn add_reactions(
     ctx: &Context,
     msg: &Message,
     emojis: Vec<ReactionType>,
+) -> Result<(), Error> {
+    add_reactions_with_delay(ctx, msg, emojis, Duration::ZERO).await
+}
+
+/// Adds reactions in a non-blocking fashion, waiting `delay` between each
+/// reaction.
+///
+/// Adding many reactions back-to-back can hit Discord's rate limit for
+/// reactions. A small delay spreads the requests out, at the cost of taking
+/// longer to add all reactions. No delay is added before the first reaction.
+///
+/// See [`add_reactions`] for more details.
+pub async fn add_reactions_with_delay(
+    ctx: &Context,
+    msg: &Message,
+    emojis: Vec<ReactionType>,
+    delay: Duration,
 ) -> Result<(), Error> {
     let channel_id = msg.channel_id;
     let msg_id = msg.id;
     let http = ctx.http.clone();
 
     tokio::spawn(async move {
-        for emoji in emojis {
-            http.create_reaction(channel_id.0, msg_id.0, &emoji).await?;
-        }
+        for (i, emoji) in emojis.iter().enumerate() {
+            if i > 0 && !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
 
-        Result::<_, Error>::Ok(())
+            if let Err(_e) = http.create_reaction(channel_id.0, msg_id.0, emoji).await {
+                debug!(%emoji, error = %_e, "failed to add reaction");
+            }
+        }
     });
 
     Ok(())
@@ -37,15 +69,304 @@ pub async fn add_reactions(
 /// This blocks the execution of code until all reactions are added. The order
 /// of `emojis` is preserved.
 ///
-/// See [`add_reactions`] to add reactions in a non-blocking fashion.
+/// If a reaction can't be added, for example because it's a custom emoji the
+/// current user/bot can't use, the remaining reactions are still added and the
+/// first error is returned. See [`try_add_reactions_blocking`] to get every
+/// reaction that failed.
+///
+/// See [`add_reactions`] to add reactions in a non-blocking fashion and
+/// [`add_reactions_blocking_with_delay`] to wait between adding reactions.
 pub async fn add_reactions_blocking(
     ctx: &Context,
     msg: &Message,
     emojis: &[ReactionType],
+) -> Result<(), Error> {
+    add_reactions_blocking_with_delay(ctx, msg, emojis, Duration::ZERO).await
+}
+
+/// Adds reactions in a blocking fashion, waiting `delay` between each
+/// reaction.
+///
+/// This trades a longer wait until all reactions are added for fewer bursts
+/// of requests, which helps avoid Discord's rate limit for reactions. No delay
+/// is added before the first reaction.
+///
+/// See [`add_reactions_blocking`] for more details.
+pub async fn add_reactions_blocking_with_delay(
+    ctx: &Context,
+    msg: &Message,
+    emojis: &[ReactionType],
+    delay: Duration,
+) -> Result<(), Error> {
+    match add_reactions_collecting_failures(ctx, msg, emojis, delay).await.into_iter().next() {
+        Some((_, e)) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Adds reactions in a blocking fashion, reporting every reaction that
+/// couldn't be added.
+///
+/// This works like [`add_reactions_blocking`], but an
+/// [`Error::FailedReactions`] listing each failed reaction along with its error
+/// is returned instead of only the first error.
+///
+/// [`Error::FailedReactions`]: crate::Error::FailedReactions
+pub async fn try_add_reactions_blocking(
+    ctx: &Context,
+    msg: &Message,
+    emojis: &[ReactionType],
+) -> Result<(), crate::Error> {
+    try_add_reactions_blocking_with_delay(ctx, msg, emojis, Duration::ZERO).await
+}
+
+/// Adds reactions in a blocking fashion, waiting `delay` between each
+/// reaction and reporting every reaction that couldn't be added.
+///
+/// See [`try_add_reactions_blocking`] and
+/// [`add_reactions_blocking_with_delay`] for more details.
+pub async fn try_add_reactions_blocking_with_delay(
+    ctx: &Context,
+    msg: &Message,
+    emojis: &[ReactionType],
+    delay: Duration,
+) -> Result<(), crate::Error> {
+    let failures = add_reactions_collecting_failures(ctx, msg, emojis, delay).await;
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::Error::FailedReactions(failures))
+    }
+}
+
+/// Adds each reaction in turn and returns the ones that couldn't be added.
+async fn add_reactions_collecting_failures(
+    ctx: &Context,
+    msg: &Message,
+    emojis: &[ReactionType],
+    delay: Duration,
+) -> Vec<(ReactionType, Error)> {
+    let mut failures = Vec::new();
+
+    for (i, emoji) in emojis.iter().enumerate() {
+        if i > 0 && !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+
+        if let Err(e) = ctx.http.create_reaction(msg.channel_id.0, msg.id.0, emoji).await {
+            debug!(%emoji, error = %e, "failed to add reaction");
+            failures.push((emoji.clone(), e));
+        }
+    }
+
+    failures
+}
+
+/// The maximum number of messages deleted by a single bulk delete request.
+const BULK_DELETE_LIMIT: usize = 100;
+
+/// The age after which Discord refuses to bulk delete messages, two weeks. A
+/// minute is taken off to allow for clock drift.
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60 - 60);
+
+/// Deletes messages from a channel and returns the number of deleted messages.
+///
+/// Messages newer than two weeks are deleted with Discord's bulk delete
+/// endpoint, in batches of 100. Older messages can't be bulk deleted, so they
+/// are deleted one by one. Duplicate IDs are ignored.
+///
+/// Deleting messages sent by other users requires the `Manage Messages`
+/// permission.
+///
+/// ## Errors
+///
+/// Returns the first error returned by Discord. Messages deleted before the
+/// error remain deleted.
+pub async fn delete_messages(
+    ctx: &Context,
+    channel_id: ChannelId,
+    messages: &[MessageId],
+) -> Result<usize, Error> {
+    let mut messages = messages.to_vec();
+    messages.sort_unstable();
+    messages.dedup();
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let cutoff = now.saturating_sub(BULK_DELETE_MAX_AGE).as_secs() as i64;
+
+    let (recent, old): (Vec<_>, Vec<_>) =
+        messages.into_iter().partition(|id| id.created_at().unix_timestamp() > cutoff);
+
+    for chunk in recent.chunks(BULK_DELETE_LIMIT) {
+        channel_id.delete_messages(&ctx.http, chunk).await?;
+    }
+
+    for id in &old {
+        channel_id.delete_message(&ctx.http, id).await?;
+    }
+
+    Ok(recent.len() + old.len())
+}
+
+/// Returns the current user's/bot's permissions in a guild channel.
+///
+/// The cache is used if the `cache` feature is enabled and the channel and
+/// its guild are cached. Otherwise, the channel, its guild and the current
+/// user's member are fetched over the REST API, which takes a few requests.
+///
+/// `None` is returned if the permissions can't be determined, for example if
+/// the channel is not a guild channel or a request fails.
+///
+/// This can be used to check permissions before doing something that would
+/// fail without them.
+pub async fn channel_permissions(ctx: &Context, channel_id: ChannelId) -> Option<Permissions> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(channel) = ctx.cache.guild_channel(channel_id) {
+            if let Ok(permissions) =
+                channel.permissions_for_user(&ctx.cache, ctx.cache.current_user_id())
+            {
+                return Some(permissions);
+            }
+        }
+    }
+
+    let channel = ctx.http.get_channel(channel_id.0).await.ok()?.guild()?;
+    let guild = channel.guild_id.to_partial_guild(&ctx.http).await.ok()?;
+
+    #[cfg(feature = "cache")]
+    let current_user_id = ctx.cache.current_user_id();
+    #[cfg(not(feature = "cache"))]
+    let current_user_id = ctx.http.get_current_user().await.ok()?.id;
+
+    let member = guild.id.member(ctx, current_user_id).await.ok()?;
+
+    guild.user_permissions_in(&channel, &member).ok()
+}
+
+/// Removes the current user's/bot's reactions from a message.
+///
+/// Only the reactions of the current user/bot for `emojis` are removed, so the
+/// `Manage Messages` permission is not required. Reactions of other users are
+/// left as they are.
+pub async fn remove_own_reactions(
+    ctx: &Context,
+    msg: &Message,
+    emojis: &[ReactionType],
 ) -> Result<(), Error> {
     for emoji in emojis {
-        ctx.http.create_reaction(msg.channel_id.0, msg.id.0, emoji).await?;
+        ctx.http.delete_reaction(msg.channel_id.0, msg.id.0, None, emoji).await?;
     }
 
     Ok(())
 }
+
+/// Named colours recognised by [`parse_colour`].
+///
+/// It contains the basic CSS colours along with a few common CSS colours and
+/// Discord's brand colours.
+const NAMED_COLOURS: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("silver", 0xC0C0C0),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("white", 0xFFFFFF),
+    ("maroon", 0x800000),
+    ("red", 0xFF0000),
+    ("purple", 0x800080),
+    ("fuchsia", 0xFF00FF),
+    ("magenta", 0xFF00FF),
+    ("green", 0x008000),
+    ("lime", 0x00FF00),
+    ("olive", 0x808000),
+    ("yellow", 0xFFFF00),
+    ("navy", 0x000080),
+    ("blue", 0x0000FF),
+    ("teal", 0x008080),
+    ("aqua", 0x00FFFF),
+    ("cyan", 0x00FFFF),
+    ("orange", 0xFFA500),
+    ("pink", 0xFFC0CB),
+    ("gold", 0xFFD700),
+    ("brown", 0xA52A2A),
+    ("violet", 0xEE82EE),
+    ("indigo", 0x4B0082),
+    ("blurple", 0x5865F2),
+    ("greyple", 0x99AAB5),
+    ("darkbutnotblack", 0x2C2F33),
+    ("notquiteblack", 0x23272A),
+];
+
+/// Parses a colour from a string.
+///
+/// The following formats are recognised:
+/// - a colour name, like `red` or `blurple` (case, spaces, underscores and
+///   hyphens are ignored)
+/// - a hex code with or without `#` or `0x`, like `#ff0000`, `0xff0000` or
+///   `ff0000` (the three digit form, like `#f00`, requires `#`)
+/// - an RGB triple, like `rgb(255, 0, 0)`
+///
+/// `None` is returned if the string is not recognised.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::utils::Colour;
+/// # use serenity_utils::misc::parse_colour;
+/// #
+/// assert_eq!(parse_colour("red"), Some(Colour::new(0xff0000)));
+/// assert_eq!(parse_colour("#ff0000"), Some(Colour::new(0xff0000)));
+/// assert_eq!(parse_colour("rgb(255, 0, 0)"), Some(Colour::new(0xff0000)));
+/// assert_eq!(parse_colour("not a colour"), None);
+/// ```
+pub fn parse_colour(arg: &str) -> Option<Colour> {
+    let arg = arg.trim().to_lowercase();
+
+    if let Some(hex) = arg.strip_prefix('#') {
+        return match hex.len() {
+            3 => parse_hex(hex).map(|v| {
+                // Expands each digit, so `f00` becomes `ff0000`.
+                let (r, g, b) = ((v >> 8) & 0xF, (v >> 4) & 0xF, v & 0xF);
+
+                Colour::from_rgb((r * 17) as u8, (g * 17) as u8, (b * 17) as u8)
+            }),
+            6 => parse_hex(hex).map(Colour::new),
+            _ => None,
+        };
+    }
+
+    if let Some(hex) = arg.strip_prefix("0x") {
+        return if hex.len() == 6 { parse_hex(hex).map(Colour::new) } else { None };
+    }
+
+    if let Some(rgb) = arg.strip_prefix("rgb(").and_then(|a| a.strip_suffix(')')) {
+        let values =
+            rgb.split(',').map(|v| v.trim().parse::<u8>().ok()).collect::<Option<Vec<_>>>()?;
+
+        return match values[..] {
+            [r, g, b] => Some(Colour::from_rgb(r, g, b)),
+            _ => None,
+        };
+    }
+
+    let name = arg.replace([' ', '_', '-'], "");
+    if let Some((_, value)) = NAMED_COLOURS.iter().find(|(n, _)| *n == name) {
+        return Some(Colour::new(*value));
+    }
+
+    if arg.len() == 6 {
+        return parse_hex(&arg).map(Colour::new);
+    }
+
+    None
+}
+
+/// Parses hex digits, rejecting signs that `from_str_radix` would accept.
+fn parse_hex(hex: &str) -> Option<u32> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16).ok()
+}
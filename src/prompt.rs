@@ -2,8 +2,9 @@
 //!
 //! ## Examples
 //!
-//! This library provides two types of prompts: message-based and reaction-based.
-//! An example for both is given below.
+//! This library provides two main types of prompts: message-based and
+//! reaction-based. An example for both is given below. For choosing among many
+//! options, see [`select_prompt`].
 //!
 //! ### Message Prompt
 //!
@@ -43,12 +44,28 @@
 //! }
 //! ```
 //!
+//! Any prompt can be cancelled early with [`cancellable`].
+//!
+//! ## Timeouts
+//!
+//! A timeout of zero, like `0.0` or [`Duration::ZERO`], means the prompt waits
+//! for the user's response indefinitely. Previously, such prompts timed out
+//! immediately. Use [`cancellable`] to stop a prompt without a timeout.
+//!
 //! For more in-depth usage and examples, see individual functions.
+//!
+//! [`Duration::ZERO`]: std::time::Duration::ZERO
 
+mod cancel;
 mod message;
 mod reaction;
+mod select;
 
+#[doc(inline)]
+pub use cancel::*;
 #[doc(inline)]
 pub use message::*;
 #[doc(inline)]
 pub use reaction::*;
+#[doc(inline)]
+pub use select::*;
@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::pin::pin;
+
+use serenity::futures::future::{self, Either};
+
+use crate::Error;
+
+/// Runs a prompt until it finishes or until `cancel` resolves.
+///
+/// This works with any prompt of this module. If `cancel` resolves first, the
+/// prompt is dropped, which stops it from waiting for the user's response,
+/// and [`Error::Cancelled`] is returned. `cancel` can be any future, like a
+/// receiver of a channel or a shutdown signal.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::{cancellable, message_prompt_content}, Error};
+/// # use tokio::sync::oneshot::Receiver;
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message, cancel: Receiver<()>) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "What is your favourite colour?").await?;
+///
+///     let prompt = message_prompt_content(ctx, &prompt_msg, &msg.author, 120.0);
+///
+///     // User's optional response, unless the prompt is cancelled before that.
+///     let optional_content = cancellable(prompt, cancel).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::Cancelled`] if `cancel` resolves before the prompt
+/// finishes.
+///
+/// [`Error::Cancelled`]: crate::error::Error::Cancelled
+pub async fn cancellable<P, C>(prompt: P, cancel: C) -> Result<P::Output, Error>
+where
+    P: Future,
+    C: Future,
+{
+    match future::select(pin!(prompt), pin!(cancel)).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => {
+            debug!("prompt cancelled");
+
+            Err(Error::Cancelled)
+        },
+    }
+}
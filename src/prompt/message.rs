@@ -20,11 +20,16 @@
 //! }
 //! ```
 
+use std::ops::RangeInclusive;
 use std::time::Duration;
 
-use serenity::model::prelude::{Message, User};
+use serenity::collector::CollectReply;
+use serenity::futures::StreamExt;
+use serenity::model::prelude::{ChannelId, Message, User};
 use serenity::prelude::Context;
 
+use crate::error::Error;
+
 /// Creates a message prompt to get the next message a user sends.
 ///
 /// Only messages sent in the channel of the original message are considered.
@@ -58,11 +63,135 @@ pub async fn message_prompt(
     user: &User,
     timeout: f32,
 ) -> Option<Message> {
-    user.await_reply(&ctx)
-        .channel_id(msg.channel_id)
-        .timeout(Duration::from_secs_f32(timeout))
+    message_prompt_dur(ctx, msg, user, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`message_prompt`], but takes the timeout as a [`Duration`].
+pub async fn message_prompt_dur(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: Duration,
+) -> Option<Message> {
+    message_prompt_in_dur(ctx, msg.channel_id, user, timeout).await
+}
+
+/// Creates a message prompt to get the next message a user sends in
+/// `channel_id`.
+///
+/// Unlike [`message_prompt`], the channel to listen in doesn't have to be the
+/// channel of the prompt. This allows flows like asking in one channel and
+/// collecting the answer in another. The bot waits for a message for
+/// `timeout` seconds only. `None` is returned if the user does not send a
+/// message in the channel.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::message_prompt_in, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let submissions = ChannelId(8);
+///
+///     msg.channel_id.say(&ctx.http, "Send your entry in the submissions channel.").await?;
+///
+///     // Optional `Message` object of user's entry.
+///     let entry = message_prompt_in(ctx, submissions, &msg.author, 120.0).await;
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn message_prompt_in(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    timeout: f32,
+) -> Option<Message> {
+    message_prompt_in_dur(ctx, channel_id, user, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`message_prompt_in`], but takes the timeout as a [`Duration`].
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn message_prompt_in_dur(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    timeout: Duration,
+) -> Option<Message> {
+    let reply = reply_collector(ctx, channel_id, user, timeout).await.map(|m| m.as_ref().clone());
+
+    debug!(answered = reply.is_some(), "message prompt finished");
+
+    reply
+}
+
+/// Returns a collector for the next message `user` sends in `channel_id`.
+///
+/// A zero `timeout` means the collector waits indefinitely.
+fn reply_collector(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    timeout: Duration,
+) -> CollectReply {
+    let collector = user.await_reply(ctx).channel_id(channel_id);
+
+    if timeout.is_zero() {
+        collector
+    } else {
+        collector.timeout(timeout)
+    }
+}
+
+/// Creates a message prompt to get the next message a user sends as a reply to
+/// `msg`.
+///
+/// Unlike [`message_prompt`], messages which are not replies to `msg` are
+/// ignored. This avoids capturing unrelated messages in busy channels. The bot
+/// waits for a reply for `timeout` seconds only. `None` is returned if the
+/// user does not reply to the message.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::message_prompt_reply, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg =
+///         ChannelId(7).say(&ctx.http, "Reply to this message with your name.").await?;
+///
+///     // Optional `Message` object of user's reply to the message.
+///     let optional_msg = message_prompt_reply(ctx, &prompt_msg, &msg.author, 30.0).await;
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn message_prompt_reply(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+) -> Option<Message> {
+    let msg_id = msg.id;
+    let timeout = Duration::from_secs_f32(timeout);
+
+    let reply = reply_collector(ctx, msg.channel_id, user, timeout)
+        .filter(move |m| m.message_reference.as_ref().and_then(|r| r.message_id) == Some(msg_id))
         .await
-        .map(|m| m.as_ref().clone())
+        .map(|m| m.as_ref().clone());
+
+    debug!(answered = reply.is_some(), "reply prompt finished");
+
+    reply
 }
 
 /// Creates a message prompt to get the content of the next message a user sends.
@@ -98,9 +227,370 @@ pub async fn message_prompt_content(
     user: &User,
     timeout: f32,
 ) -> Option<String> {
-    user.await_reply(&ctx)
-        .channel_id(msg.channel_id)
-        .timeout(Duration::from_secs_f32(timeout))
+    message_prompt_content_dur(ctx, msg, user, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`message_prompt_content`], but takes the timeout as a [`Duration`].
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn message_prompt_content_dur(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: Duration,
+) -> Option<String> {
+    let content =
+        reply_collector(ctx, msg.channel_id, user, timeout).await.map(|m| m.content.clone());
+
+    debug!(answered = content.is_some(), "message prompt finished");
+
+    content
+}
+
+/// Same as [`message_prompt_content`], but can delete the user's message.
+///
+/// If `delete` is `true`, the user's message is deleted after its content is
+/// taken, which keeps the channel clean. Deleting another user's message
+/// requires the `Manage Messages` permission. If the message can't be
+/// deleted, it's left as it is and the content is still returned.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::message_prompt_content_cleanup, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "What is the password?").await?;
+///
+///     let optional_content =
+///         message_prompt_content_cleanup(ctx, &prompt_msg, &msg.author, 30.0, true).await;
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn message_prompt_content_cleanup(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+    delete: bool,
+) -> Option<String> {
+    let reply = message_prompt(ctx, msg, user, timeout).await?;
+
+    if delete {
+        if let Err(_e) = reply.delete(&ctx.http).await {
+            debug!(error = %_e, "failed to delete message prompt answer");
+        }
+    }
+
+    Some(reply.content)
+}
+
+/// Creates a message prompt that waits until the user sends a valid answer.
+///
+/// Each message the user sends in the channel of `msg` is checked with
+/// `validator`. If it returns `Ok`, the value is returned. If it returns `Err`,
+/// the error is sent as a reply to the user's message and the next message is
+/// awaited, up to `retries` more times. Each message is awaited for `timeout`
+/// seconds.
+///
+/// `None` is returned if the user doesn't send a message in time or if no
+/// valid answer is sent within the retries.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::message_prompt_validated, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "How old are you?").await?;
+///
+///     let optional_age = message_prompt_validated(ctx, &prompt_msg, &msg.author, 30.0, 2, |s| {
+///         s.trim().parse::<u8>().map_err(|_| "Please send a number.".to_string())
+///     })
+///     .await;
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn message_prompt_validated<F, T>(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+    retries: usize,
+    validator: F,
+) -> Option<T>
+where
+    F: Fn(&str) -> Result<T, String>,
+{
+    let timeout = Duration::from_secs_f32(timeout);
+
+    for _attempt in 0..=retries {
+        let reply = message_prompt_dur(ctx, msg, user, timeout).await?;
+
+        match validator(&reply.content) {
+            Ok(value) => return Some(value),
+            Err(error) => {
+                debug!(attempt = _attempt, "message prompt answer rejected");
+
+                // The user can still answer if the error can't be sent.
+                let _ = reply.reply(&ctx.http, error).await;
+            },
+        }
+    }
+
+    None
+}
+
+/// Creates a message prompt to get a number within `range` from the user.
+///
+/// Messages the user sends in the channel of `msg` are read until one of them
+/// is an integer within `range`, which is returned. Other messages, including
+/// numbers out of range, are ignored. Numbers too large or too small to fit
+/// in an `i64` are treated as invalid and ignored as well. Leading and
+/// trailing whitespace is ignored.
+///
+/// The bot waits for a valid number for `timeout` seconds in total. `None` is
+/// returned if the user doesn't send one in time.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::number_prompt, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "Pick a number between 1 and 10.").await?;
+///
+///     let optional_number = number_prompt(ctx, &prompt_msg, &msg.author, 1..=10, 30.0).await;
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn number_prompt(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    range: RangeInclusive<i64>,
+    timeout: f32,
+) -> Option<i64> {
+    let timeout = Duration::from_secs_f32(timeout);
+    let parse =
+        move |content: &str| content.trim().parse::<i64>().ok().filter(|n| range.contains(n));
+
+    let filter = parse.clone();
+    let number = reply_collector(ctx, msg.channel_id, user, timeout)
+        .filter(move |m| filter(&m.content).is_some())
         .await
-        .map(|m| m.content.clone())
+        .and_then(|m| parse(&m.content));
+
+    debug!(answered = number.is_some(), "number prompt finished");
+
+    number
+}
+
+/// Sends `question` in the channel and gets the content of the user's reply.
+///
+/// This sends the prompt message itself and then behaves like
+/// [`message_prompt_content`]. Use that function if the prompt message has
+/// already been sent.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::Message,
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::ask, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let question = "What is your favourite colour?";
+///
+///     // User's optional response to the question.
+///     let optional_content = ask(ctx, msg.channel_id, &msg.author, question, 30.0).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::SerenityError`] if the question can't be sent.
+///
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+pub async fn ask(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    question: &str,
+    timeout: f32,
+) -> Result<Option<String>, Error> {
+    let prompt_msg = channel_id.say(&ctx.http, question).await?;
+
+    Ok(message_prompt_content(ctx, &prompt_msg, user, timeout).await)
+}
+
+/// Creates a message prompt to get the next message a user sends in DMs.
+///
+/// The user's DM channel is opened, or reused if it exists, and the next
+/// message the user sends there is returned. The bot waits for a message for
+/// `timeout` seconds only. `Ok(None)` is returned if the user does not send a
+/// message. The `DIRECT_MESSAGES` intent is required to receive the message.
+///
+/// Nothing is sent to the user. See [`dm_ask`] to ask a question in DMs first.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{model::prelude::Message, prelude::Context};
+/// # use serenity_utils::{prompt::dm_message_prompt, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     msg.author.dm(&ctx.http, |m| m.content("What should your nickname be?")).await?;
+///
+///     let optional_msg = dm_message_prompt(ctx, &msg.author, 60.0).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::SerenityError`] if the DM channel can't be opened.
+///
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn dm_message_prompt(
+    ctx: &Context,
+    user: &User,
+    timeout: f32,
+) -> Result<Option<Message>, Error> {
+    let channel = user.create_dm_channel(ctx).await?;
+    let timeout = Duration::from_secs_f32(timeout);
+
+    let reply = reply_collector(ctx, channel.id, user, timeout).await.map(|m| m.as_ref().clone());
+
+    debug!(answered = reply.is_some(), "DM prompt finished");
+
+    Ok(reply)
+}
+
+/// Sends `question` to the user in DMs and gets the content of their reply.
+///
+/// This sends the question itself and then behaves like [`dm_message_prompt`].
+///
+/// ## Errors
+///
+/// Returns [`Error::DmsDisabled`] if the question can't be sent because the
+/// user doesn't accept direct messages from the current user/bot.
+///
+/// Returns [`Error::SerenityError`] if the DM channel can't be opened or if
+/// the question can't be sent for another reason.
+///
+/// [`Error::DmsDisabled`]: crate::error::Error::DmsDisabled
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+pub async fn dm_ask(
+    ctx: &Context,
+    user: &User,
+    question: &str,
+    timeout: f32,
+) -> Result<Option<String>, Error> {
+    send_dm(ctx, user, question).await?;
+
+    Ok(dm_message_prompt(ctx, user, timeout).await?.map(|m| m.content))
+}
+
+/// Discord's error code for a user who can't be sent messages.
+const CANNOT_MESSAGE_USER: isize = 50007;
+
+/// Sends `content` to the user in DMs.
+///
+/// [`Error::DmsDisabled`] is returned if the user doesn't accept DMs.
+pub(crate) async fn send_dm(ctx: &Context, user: &User, content: &str) -> Result<Message, Error> {
+    match user.dm(ctx, |m| m.content(content)).await.map_err(Error::from) {
+        Err(e) if e.discord_code() == Some(CANNOT_MESSAGE_USER) => Err(Error::DmsDisabled),
+        result => result,
+    }
+}
+
+/// Creates a message prompt that only accepts one of the given choices.
+///
+/// Only messages sent in the channel of the original message are considered.
+/// A message matches a choice if their contents are equal, ignoring case and
+/// surrounding whitespace. Messages not matching any choice are ignored.
+///
+/// It returns the index of the matching choice in `choices`. `None` is returned
+/// if the user does not send a matching message within `timeout` seconds.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::choice_prompt, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "Type yes, no or maybe.").await?;
+///
+///     let choices = ["yes", "no", "maybe"];
+///     match choice_prompt(ctx, &prompt_msg, &msg.author, 30.0, &choices).await {
+///         Some(0) => {
+///             // Yes!
+///         },
+///         Some(_) => {
+///             // No or maybe!
+///         },
+///         None => {
+///             // No response.
+///         },
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn choice_prompt(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+    choices: &[&str],
+) -> Option<usize> {
+    let timeout = Duration::from_secs_f32(timeout);
+
+    let mut builder = user.await_replies(ctx).channel_id(msg.channel_id);
+    if !timeout.is_zero() {
+        builder = builder.timeout(timeout);
+    }
+
+    let mut collector = builder.build();
+
+    while let Some(message) = collector.next().await {
+        let content = message.content.trim().to_lowercase();
+
+        if let Some(index) = choices.iter().position(|c| c.trim().to_lowercase() == content) {
+            debug!(index, "choice prompt answered");
+            return Some(index);
+        }
+    }
+
+    debug!("choice prompt timed out");
+
+    None
 }
@@ -20,21 +20,33 @@
 //! }
 //! ```
 
+use std::pin::pin;
 use std::time::Duration;
 
 use serenity::collector::ReactionAction;
+use serenity::futures::future::{self, Either};
 use serenity::futures::StreamExt;
-use serenity::model::prelude::{Message, ReactionType, User};
+use serenity::model::prelude::{ChannelId, Message, ReactionType, User};
+use serenity::model::Permissions;
 use serenity::prelude::Context;
 
+use super::message::{message_prompt_dur, send_dm};
 use crate::error::Error;
-use crate::misc::add_reactions;
+use crate::formatting::mention_user;
+use crate::misc::{add_reactions, channel_permissions, remove_own_reactions};
 
 /// Creates a reaction prompt to get user's reaction.
 ///
 /// Reactions are collected on the specified message. Only messages sent by `user`
 /// are considered. Reactions are only considered for `timeout` seconds.
 ///
+/// Once the user reacts or the prompt times out, the reactions added by the
+/// current user/bot are removed. See [`reaction_prompt_cleanup`] to keep them.
+///
+/// This returns the index of the emoji. See [`reaction_prompt_map`] to get a
+/// value associated with the emoji instead, which avoids mapping indices back
+/// to their meaning.
+///
 /// ## Example
 ///
 /// ```
@@ -68,10 +80,14 @@ use crate::misc::add_reactions;
 ///
 /// ## Errors
 ///
-/// Returns [`Error::SerenityError`] if cache is enabled and the current
-/// user does not have the required permissions to add reactions.
+/// Returns [`Error::MissingPermissions`] if the current user does not have the
+/// permission to add reactions in the channel. The permissions are checked
+/// with [`channel_permissions`]. If they can't be determined, the reactions are
+/// added anyway.
 ///
 /// Returns [`Error::TimeoutError`] if user does not react at all.
+///
+/// [`channel_permissions`]: crate::misc::channel_permissions
 pub async fn reaction_prompt(
     ctx: &Context,
     msg: &Message,
@@ -79,31 +95,308 @@ pub async fn reaction_prompt(
     emojis: &[ReactionType],
     timeout: f32,
 ) -> Result<(usize, ReactionType), Error> {
+    reaction_prompt_dur(ctx, msg, user, emojis, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`reaction_prompt`], but takes the timeout as a [`Duration`].
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`].
+pub async fn reaction_prompt_dur(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: &[ReactionType],
+    timeout: Duration,
+) -> Result<(usize, ReactionType), Error> {
+    reaction_prompt_cleanup(ctx, msg, user, emojis, timeout, true).await
+}
+
+/// Creates a reaction prompt that returns the value associated with the
+/// user's reaction.
+///
+/// The emojis of `choices` are added to the message in order. This behaves in
+/// the same way as [`reaction_prompt`], except the value paired with the
+/// emoji is returned instead of its index.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message, ReactionType},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::reaction_prompt_map, Error};
+/// #
+/// #[derive(Clone)]
+/// enum Action {
+///     Attack,
+///     Defend,
+/// }
+///
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let choices = [
+///         (ReactionType::from('🗡'), Action::Attack),
+///         (ReactionType::from('🛡'), Action::Defend),
+///     ];
+///
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "What will you do?").await?;
+///
+///     match reaction_prompt_map(ctx, &prompt_msg, &msg.author, &choices, 30.0).await? {
+///         Action::Attack => { /* Attack! */ },
+///         Action::Defend => { /* Defend! */ },
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`].
+pub async fn reaction_prompt_map<T: Clone>(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    choices: &[(ReactionType, T)],
+    timeout: f32,
+) -> Result<T, Error> {
+    let emojis = choices.iter().map(|(emoji, _)| emoji.clone()).collect::<Vec<_>>();
+    let (index, _) = reaction_prompt(ctx, msg, user, &emojis, timeout).await?;
+
+    Ok(choices[index].1.clone())
+}
+
+/// Same as [`reaction_prompt_dur`], but lets you keep the prompt's reactions.
+///
+/// If `cleanup` is `true`, the reactions added by the current user/bot are
+/// removed once the user reacts or the prompt times out. This is what
+/// [`reaction_prompt`] does. Reactions are removed with
+/// [`remove_own_reactions`], so the `Manage Messages` permission is not
+/// required.
+///
+/// If `cleanup` is `false`, the reactions are left on the message.
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`]. Failing to remove
+/// reactions is not an error.
+///
+/// [`remove_own_reactions`]: crate::misc::remove_own_reactions
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn reaction_prompt_cleanup(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: &[ReactionType],
+    timeout: Duration,
+    cleanup: bool,
+) -> Result<(usize, ReactionType), Error> {
+    if !can_add_reactions(ctx, msg).await {
+        debug!("missing permission to add reactions");
+        return Err(Error::MissingPermissions(Permissions::ADD_REACTIONS));
+    }
+
     add_reactions(ctx, msg, emojis.to_vec()).await?;
 
-    let mut collector = user
-        .await_reactions(&ctx)
-        .message_id(msg.id)
-        .timeout(Duration::from_secs_f32(timeout))
-        .build();
+    let result = collect_reaction(ctx, msg, user, emojis, timeout).await;
+
+    if cleanup {
+        let _ = remove_own_reactions(ctx, msg, emojis).await;
+    }
+
+    result
+}
+
+/// Creates a reaction prompt on a message that already has the reactions.
+///
+/// This behaves like [`reaction_prompt`], but the reactions aren't added to
+/// the message and aren't removed afterwards. It's useful for persistent
+/// messages that already have the reactions, or for messages in channels where
+/// the current user/bot can't add reactions.
+///
+/// ## Errors
+///
+/// Returns [`Error::TimeoutError`] if user does not react at all.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn reaction_prompt_no_add(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: &[ReactionType],
+    timeout: f32,
+) -> Result<(usize, ReactionType), Error> {
+    collect_reaction(ctx, msg, user, emojis, Duration::from_secs_f32(timeout)).await
+}
+
+/// Waits for `user` to react to `msg` with one of `emojis`.
+async fn collect_reaction(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: &[ReactionType],
+    timeout: Duration,
+) -> Result<(usize, ReactionType), Error> {
+    let mut builder = user.await_reactions(ctx).message_id(msg.id);
+    if !timeout.is_zero() {
+        builder = builder.timeout(timeout);
+    }
+
+    let mut collector = builder.build();
 
     while let Some(action) = collector.next().await {
         if let ReactionAction::Added(reaction) = action.as_ref() {
-            if emojis.contains(&reaction.emoji) {
-                return Ok((
-                    emojis.iter().position(|p| p == &reaction.emoji).unwrap(),
-                    reaction.emoji.clone(),
-                ));
+            if let Some(index) = emojis.iter().position(|p| p == &reaction.emoji) {
+                debug!(index, "reaction prompt answered");
+                return Ok((index, reaction.emoji.clone()));
             }
         }
     }
 
+    debug!("reaction prompt timed out");
+
     Err(Error::TimeoutError)
 }
 
+/// Sends `question` in the channel and gets the user's reaction to it.
+///
+/// This sends the prompt message itself and then behaves like
+/// [`reaction_prompt`]. Use that function if the prompt message has already
+/// been sent.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{Message, ReactionType},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::ask_reaction, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let emojis = [ReactionType::from('🐶'), ReactionType::from('🐱')];
+///
+///     let (idx, _) =
+///         ask_reaction(ctx, msg.channel_id, &msg.author, "Dogs or cats?", &emojis, 30.0).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::SerenityError`] if the question can't be sent. Other
+/// errors are the same as [`reaction_prompt`].
+///
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+pub async fn ask_reaction(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    question: &str,
+    emojis: &[ReactionType],
+    timeout: f32,
+) -> Result<(usize, ReactionType), Error> {
+    let prompt_msg = channel_id.say(&ctx.http, question).await?;
+
+    reaction_prompt(ctx, &prompt_msg, user, emojis, timeout).await
+}
+
+/// Where a prompt was shown to the user.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PromptLocation {
+    /// The prompt was sent to the user in DMs.
+    Dm,
+    /// The prompt was sent in the channel, as the user's DMs are closed.
+    Channel,
+}
+
+/// Asks `question` in the user's DMs and gets their reaction to it, falling
+/// back to the channel if the user's DMs are closed.
+///
+/// This keeps prompts, like confirmations, out of public channels when
+/// possible. If the user doesn't accept direct messages from the current
+/// user/bot, the question is sent in `channel_id` instead, mentioning the user
+/// with a note that they couldn't be sent a DM.
+///
+/// Apart from the location, it works like [`reaction_prompt`]. The returned
+/// [`PromptLocation`] tells where the prompt was shown. Collecting reactions
+/// in DMs requires the `DIRECT_MESSAGE_REACTIONS` intent.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{Message, ReactionType},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::private_reaction_prompt, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let emojis = [ReactionType::from('✅'), ReactionType::from('❌')];
+///     let question = "Do you want to delete your data?";
+///
+///     let (idx, _, location) =
+///         private_reaction_prompt(ctx, msg.channel_id, &msg.author, question, &emojis, 30.0)
+///             .await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::SerenityError`] if the question can't be sent in DMs for
+/// a reason other than closed DMs, or if it can't be sent in the channel.
+/// Other errors are the same as [`reaction_prompt`].
+///
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+pub async fn private_reaction_prompt(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    question: &str,
+    emojis: &[ReactionType],
+    timeout: f32,
+) -> Result<(usize, ReactionType, PromptLocation), Error> {
+    let (prompt_msg, location) = match send_dm(ctx, user, question).await {
+        Ok(m) => (m, PromptLocation::Dm),
+        Err(Error::DmsDisabled) => {
+            debug!("DMs closed, prompting in channel");
+
+            let content = format!(
+                "{} I couldn't send you a direct message, so I'm asking here.\n{}",
+                mention_user(user.id),
+                question
+            );
+
+            (channel_id.say(&ctx.http, content).await?, PromptLocation::Channel)
+        },
+        Err(e) => return Err(e),
+    };
+
+    let (index, emoji) = reaction_prompt(ctx, &prompt_msg, user, emojis, timeout).await?;
+
+    Ok((index, emoji, location))
+}
+
+/// Checks whether the current user can add reactions to `msg`.
+///
+/// Reactions can always be added in direct messages. If the permissions can't
+/// be determined, `true` is returned.
+///
+/// `msg.guild_id` can't be used to skip the check for direct messages, as it
+/// is only set on messages received over the gateway, not on messages sent by
+/// the current user/bot.
+async fn can_add_reactions(ctx: &Context, msg: &Message) -> bool {
+    channel_permissions(ctx, msg.channel_id).await.map_or(true, |p| p.add_reactions())
+}
+
 /// A special reaction prompt to check if user reacts with yes or no.
 ///
-/// ✅ is used for yes and ❌ is used for no.
+/// ✅ is used for yes and ❌ is used for no. See [`yes_or_no_prompt_with`] to
+/// use other emojis.
 ///
 /// This function behaves in same way as [`reaction_prompt`] except for the
 /// return type. If the user reacts with the yes emoji, the Ok value is `true`.
@@ -143,7 +436,257 @@ pub async fn yes_or_no_prompt(
     user: &User,
     timeout: f32,
 ) -> Result<bool, Error> {
-    let emojis = [ReactionType::from('✅'), ReactionType::from('❌')];
+    yes_or_no_prompt_dur(ctx, msg, user, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`yes_or_no_prompt`], but takes the timeout as a [`Duration`].
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`].
+pub async fn yes_or_no_prompt_dur(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: Duration,
+) -> Result<bool, Error> {
+    yes_or_no_prompt_with(ctx, msg, user, '✅'.into(), '❌'.into(), timeout).await
+}
+
+/// Same as [`yes_or_no_prompt`], but uses the given emojis for yes and no, and
+/// takes the timeout as a [`Duration`].
+///
+/// This is useful for bots with their own check and cross emojis. The return
+/// value is the same as [`yes_or_no_prompt`]'s.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, EmojiId, Message, ReactionType},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::yes_or_no_prompt_with, Error};
+/// # use std::time::Duration;
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "Are you a bot?").await?;
+///
+///     let yes = ReactionType::Custom {
+///         animated: false,
+///         id: EmojiId(7),
+///         name: Some("yes".to_string()),
+///     };
+///
+///     let result = yes_or_no_prompt_with(
+///         ctx,
+///         &prompt_msg,
+///         &msg.author,
+///         yes,
+///         '👎'.into(),
+///         Duration::from_secs(30),
+///     )
+///     .await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`].
+pub async fn yes_or_no_prompt_with(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    yes: ReactionType,
+    no: ReactionType,
+    timeout: Duration,
+) -> Result<bool, Error> {
+    let emojis = [yes, no];
+
+    reaction_prompt_dur(ctx, msg, user, &emojis, timeout).await.map(|(i, _)| i == 0)
+}
+
+/// Same as [`yes_or_no_prompt`], but returns `None` if the prompt times out.
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`], except for
+/// [`Error::TimeoutError`].
+pub async fn yes_or_no_prompt_opt(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+) -> Result<Option<bool>, Error> {
+    match yes_or_no_prompt(ctx, msg, user, timeout).await {
+        Ok(answer) => Ok(Some(answer)),
+        Err(Error::TimeoutError) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// An answer to a [`tri_state_prompt`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Confirm {
+    /// The user reacted with the yes emoji.
+    Yes,
+    /// The user reacted with the no emoji.
+    No,
+    /// The user reacted with the maybe emoji.
+    Maybe,
+}
+
+/// A special reaction prompt to check if user reacts with yes, no or maybe.
+///
+/// ✅ is used for yes, ❌ is used for no and 🤷 is used for maybe. See
+/// [`tri_state_prompt_with`] to use other emojis.
+///
+/// This is useful for decisions that can be put off, like "apply", "reject"
+/// or "ask me later". `None` is returned if the prompt times out.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::{tri_state_prompt, Confirm}, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "Approve the application?").await?;
+///
+///     match tri_state_prompt(ctx, &prompt_msg, &msg.author, 30.0).await? {
+///         Some(Confirm::Yes) => { /* Approve. */ },
+///         Some(Confirm::No) => { /* Reject. */ },
+///         Some(Confirm::Maybe) | None => { /* Ask again later. */ },
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`], except for
+/// [`Error::TimeoutError`].
+pub async fn tri_state_prompt(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    timeout: f32,
+) -> Result<Option<Confirm>, Error> {
+    let emojis = [ReactionType::from('✅'), ReactionType::from('❌'), ReactionType::from('🤷')];
+
+    tri_state_prompt_with(ctx, msg, user, emojis, Duration::from_secs_f32(timeout)).await
+}
+
+/// Same as [`tri_state_prompt`], but uses the given emojis for yes, no and
+/// maybe, in that order, and takes the timeout as a [`Duration`].
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`], except for
+/// [`Error::TimeoutError`].
+pub async fn tri_state_prompt_with(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: [ReactionType; 3],
+    timeout: Duration,
+) -> Result<Option<Confirm>, Error> {
+    match reaction_prompt_dur(ctx, msg, user, &emojis, timeout).await {
+        Ok((0, _)) => Ok(Some(Confirm::Yes)),
+        Ok((1, _)) => Ok(Some(Confirm::No)),
+        Ok(_) => Ok(Some(Confirm::Maybe)),
+        Err(Error::TimeoutError) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// A user's response to a [`reaction_or_message_prompt`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum PromptResponse {
+    /// The user reacted with one of the emojis. It contains the emoji's index
+    /// in the `emojis` slice and the emoji itself.
+    Reaction(usize, ReactionType),
+    /// The user sent a message in the prompt's channel.
+    Message(Message),
+}
+
+/// Creates a prompt that accepts either a reaction or a message from the user.
+///
+/// This is useful for flows like "react with 👍 or type your reason". The
+/// emojis are added to `msg` and both the user's reactions and messages in the
+/// prompt's channel are awaited, for `timeout` seconds. Whichever comes first
+/// is returned, and the other one is no longer awaited.
+///
+/// The reactions added by the current user/bot are removed once the prompt
+/// finishes, like with [`reaction_prompt`].
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::{ChannelId, Message, ReactionType},
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::{reaction_or_message_prompt, PromptResponse}, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let emojis = [ReactionType::from('👍')];
+///
+///     let prompt_msg = ChannelId(7).say(&ctx.http, "React with 👍 or type your reason.").await?;
+///
+///     match reaction_or_message_prompt(ctx, &prompt_msg, &msg.author, &emojis, 30.0).await? {
+///         PromptResponse::Reaction(..) => { /* No reason given. */ },
+///         PromptResponse::Message(reason) => { /* Use `reason.content`. */ },
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// It can return the same errors as [`reaction_prompt`]. [`Error::TimeoutError`]
+/// is only returned if the user neither reacts nor sends a message.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn reaction_or_message_prompt(
+    ctx: &Context,
+    msg: &Message,
+    user: &User,
+    emojis: &[ReactionType],
+    timeout: f32,
+) -> Result<PromptResponse, Error> {
+    if !can_add_reactions(ctx, msg).await {
+        debug!("missing permission to add reactions");
+        return Err(Error::MissingPermissions(Permissions::ADD_REACTIONS));
+    }
+
+    add_reactions(ctx, msg, emojis.to_vec()).await?;
+
+    let timeout = Duration::from_secs_f32(timeout);
+    let reaction = collect_reaction(ctx, msg, user, emojis, timeout);
+    let message = message_prompt_dur(ctx, msg, user, timeout);
+
+    // If one of the collectors ends without a response, the other one may
+    // still get one before it times out.
+    let result = match future::select(pin!(reaction), pin!(message)).await {
+        Either::Left((Ok((index, emoji)), _)) => Ok(PromptResponse::Reaction(index, emoji)),
+        Either::Left((Err(_), message)) => {
+            message.await.map(PromptResponse::Message).ok_or(Error::TimeoutError)
+        },
+        Either::Right((Some(reply), _)) => Ok(PromptResponse::Message(reply)),
+        Either::Right((None, reaction)) => {
+            reaction.await.map(|(index, emoji)| PromptResponse::Reaction(index, emoji))
+        },
+    };
+
+    let _ = remove_own_reactions(ctx, msg, emojis).await;
 
-    reaction_prompt(ctx, msg, user, &emojis, timeout).await.map(|(i, _)| i == 0)
+    result
 }
@@ -0,0 +1,123 @@
+//! Prompts to get a user's response via a select menu.
+
+use std::time::Duration;
+
+use serenity::model::prelude::{ChannelId, InteractionResponseType, User};
+use serenity::prelude::Context;
+
+use crate::error::Error;
+
+/// The maximum number of options a select menu can have.
+const MAX_SELECT_OPTIONS: usize = 25;
+
+/// Custom ID of the select menu sent by [`select_prompt`].
+const SELECT_CUSTOM_ID: &str = "serenity_utils_select_prompt";
+
+/// Sends `question` with a select menu and gets the value the user selects.
+///
+/// `options` are `(label, value)` pairs. The label is shown to the user and
+/// the value of the selected option is returned. Only selections made by
+/// `user` within `timeout` seconds are considered, and the selection is
+/// acknowledged. `None` is returned if the user doesn't select an option.
+///
+/// A select menu is better suited than a reaction prompt when there are many
+/// options to choose from.
+///
+/// ## Example
+///
+/// ```
+/// # use serenity::{
+/// #    model::prelude::Message,
+/// #    prelude::Context,
+/// # };
+/// # use serenity_utils::{prompt::select_prompt, Error};
+/// #
+/// async fn prompt(ctx: &Context, msg: &Message) -> Result<(), Error> {
+///     let options = [
+///         ("Red".to_string(), "red".to_string()),
+///         ("Green".to_string(), "green".to_string()),
+///         ("Blue".to_string(), "blue".to_string()),
+///     ];
+///
+///     let question = "What is your favourite colour?";
+///     let optional_value =
+///         select_prompt(ctx, msg.channel_id, &msg.author, question, &options, 30.0).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`Error::Other`] if `options` is empty or has more than 25 options,
+/// the maximum allowed by Discord.
+///
+/// Returns [`Error::SerenityError`] if the message can't be sent.
+///
+/// [`Error::Other`]: crate::error::Error::Other
+/// [`Error::SerenityError`]: crate::error::Error::SerenityError
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(user_id = %user.id)))]
+pub async fn select_prompt(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user: &User,
+    question: &str,
+    options: &[(String, String)],
+    timeout: f32,
+) -> Result<Option<String>, Error> {
+    if options.is_empty() {
+        return Err(Error::msg("`options` is empty."));
+    }
+
+    if options.len() > MAX_SELECT_OPTIONS {
+        return Err(Error::msg(format!(
+            "A select menu can't have more than {} options.",
+            MAX_SELECT_OPTIONS
+        )));
+    }
+
+    let prompt_msg = channel_id
+        .send_message(&ctx.http, |m| {
+            m.content(question).components(|c| {
+                c.create_action_row(|r| {
+                    r.create_select_menu(|s| {
+                        s.custom_id(SELECT_CUSTOM_ID).options(|o| {
+                            for (label, value) in options {
+                                o.create_option(|opt| opt.label(label).value(value));
+                            }
+
+                            o
+                        })
+                    })
+                })
+            })
+        })
+        .await?;
+
+    let timeout = Duration::from_secs_f32(timeout);
+
+    let mut collector = prompt_msg.await_component_interaction(ctx).author_id(user.id);
+    if !timeout.is_zero() {
+        collector = collector.timeout(timeout);
+    }
+
+    let interaction = collector.await;
+
+    let interaction = match interaction {
+        Some(i) => i,
+        None => {
+            debug!("select prompt timed out");
+            return Ok(None);
+        },
+    };
+
+    interaction
+        .create_interaction_response(&ctx.http, |r| {
+            r.kind(InteractionResponseType::DeferredUpdateMessage)
+        })
+        .await?;
+
+    debug!("select prompt answered");
+
+    Ok(interaction.data.values.first().cloned())
+}
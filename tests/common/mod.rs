@@ -0,0 +1,127 @@
+//! Helpers shared by the integration tests.
+//!
+//! Not every test file uses every helper.
+#![allow(dead_code)]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use serenity::client::bridge::gateway::ShardMessenger;
+use serenity::futures::channel::mpsc;
+use serenity::http::HttpBuilder;
+use serenity::model::prelude::{Message, Reaction};
+use serenity::prelude::{Context, RwLock, TypeMap};
+
+/// Starts a server that answers every request with `status` and `body` and
+/// returns a context whose HTTP client sends requests to it, along with the
+/// times at which the requests were received.
+pub fn stub_context(status: &str, body: &str) -> (Context, Arc<Mutex<Vec<Instant>>>) {
+    let (status, body) = (status.to_string(), body.to_string());
+
+    stub_context_with(move |_| (status.clone(), body.clone()))
+}
+
+/// Like [`stub_context`], but the response to each request is returned by
+/// `respond`, which is given the 0-indexed number of the request.
+pub fn stub_context_with<F>(respond: F) -> (Context, Arc<Mutex<Vec<Instant>>>)
+where
+    F: Fn(usize) -> (String, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+
+            let index = {
+                let mut received = received.lock().unwrap();
+                received.push(Instant::now());
+
+                received.len() - 1
+            };
+
+            let (status, body) = respond(index);
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                Connection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    let http = HttpBuilder::new("token")
+        .proxy(format!("http://{}", address))
+        .unwrap()
+        .ratelimiter_disabled(true)
+        .build();
+
+    // The receiver is leaked so collectors can register with the shard. No
+    // events are ever sent to them, so they only end on their timeout.
+    let (tx, rx) = mpsc::unbounded();
+    std::mem::forget(rx);
+    let ctx = Context {
+        data: Arc::new(RwLock::new(TypeMap::new())),
+        shard: ShardMessenger::new(tx),
+        shard_id: 0,
+        http: Arc::new(http),
+        #[cfg(feature = "cache")]
+        cache: Arc::default(),
+    };
+
+    (ctx, requests)
+}
+
+/// Returns a message sent by the user with ID 3 in the channel with ID 1.
+pub fn message() -> Message {
+    serde_json::from_value(serde_json::json!({
+        "id": "2",
+        "channel_id": "1",
+        "author": {
+            "id": "3",
+            "username": "user",
+            "discriminator": "0001",
+            "avatar": null,
+        },
+        "attachments": [],
+        "content": "",
+        "edited_timestamp": null,
+        "embeds": [],
+        "type": 0,
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00.000Z",
+        "tts": false,
+    }))
+    .unwrap()
+}
+
+/// Returns a reaction with `emoji` by the user with ID 3 on the message with
+/// ID 2.
+pub fn reaction(emoji: &str) -> Reaction {
+    serde_json::from_value(serde_json::json!({
+        "channel_id": "1",
+        "message_id": "2",
+        "user_id": "3",
+        "emoji": {"id": null, "name": emoji},
+    }))
+    .unwrap()
+}
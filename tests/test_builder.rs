@@ -1,8 +1,13 @@
 #![allow(deprecated)]
 
+mod common;
+
+use common::message;
 use serenity::builder::*;
-use serenity::model::prelude::ReactionType;
+use serenity::model::prelude::{Embed, ReactionType};
+use serenity::utils::Colour;
 use serenity_utils::builder::prelude::*;
+use serenity_utils::Error;
 
 #[test]
 fn test_to_create_embed_author() {
@@ -110,3 +115,185 @@ fn test_to_edit_message() {
 
     assert_eq!(builder.to_edit_message().0, edit_message.0);
 }
+
+#[test]
+fn test_set_content_or_file() {
+    let mut builder = MessageBuilder::new();
+    builder.set_content_or_file("short".to_string());
+
+    assert_eq!(builder.content.as_deref(), Some("short"));
+    assert!(builder.files.is_empty());
+
+    let mut builder = MessageBuilder::new();
+    builder.set_content_or_file("a".repeat(2001));
+
+    assert_eq!(builder.content, None);
+    assert_eq!(builder.files.len(), 1);
+}
+
+#[test]
+fn test_into_messages() {
+    let mut builder = MessageBuilder::new();
+    builder.set_content("word ".repeat(1000)).set_embed_with(|e| e.set_description("embed"));
+
+    let messages = builder.into_messages();
+
+    assert_eq!(messages.len(), 3);
+    assert!(messages.iter().all(|m| m.content.as_ref().unwrap().chars().count() <= 2000));
+    assert!(messages[..2].iter().all(|m| m.embed.is_none()));
+    assert!(messages[2].embed.is_some());
+    assert_eq!(
+        messages.iter().map(|m| m.content.as_ref().unwrap().as_str()).collect::<String>(),
+        "word ".repeat(1000)
+    );
+}
+
+#[test]
+fn test_from_embed_keeps_provider_and_video() {
+    let embed: Embed = serde_json::from_value(serde_json::json!({
+        "type": "video",
+        "title": "A video",
+        "url": "https://example.com/watch",
+        "provider": {"name": "Example", "url": "https://example.com"},
+        "video": {"url": "https://example.com/embed"},
+    }))
+    .unwrap();
+
+    let builder = EmbedBuilder::from(&embed);
+
+    assert_eq!(builder.title.as_deref(), Some("A video"));
+    assert_eq!(builder.provider.as_ref().unwrap().name.as_deref(), Some("Example"));
+    assert_eq!(builder.video.as_deref(), Some("https://example.com/embed"));
+
+    let create_embed = builder.to_create_embed();
+
+    assert_eq!(
+        create_embed.0["provider"],
+        serde_json::json!({"name": "Example", "url": "https://example.com"})
+    );
+    assert_eq!(create_embed.0["video"], serde_json::json!({"url": "https://example.com/embed"}));
+}
+
+#[test]
+fn test_set_default_embed_colour() {
+    let mut builder = MessageBuilder::new();
+    builder.set_embed_with(|e| e.set_description("no colour")).set_default_embed_colour(0xff0000);
+
+    assert_eq!(builder.embed.as_ref().unwrap().colour, Some(Colour::new(0xff0000)));
+
+    let mut builder = MessageBuilder::new();
+    builder.set_embed_with(|e| e.set_colour(0x00ff00)).set_default_embed_colour(0xff0000);
+
+    assert_eq!(builder.embed.as_ref().unwrap().colour, Some(Colour::new(0x00ff00)));
+}
+
+#[test]
+fn test_attachment_urls() {
+    let mut builder = EmbedBuilder::new();
+    builder.set_image_attachment("image.png").set_thumbnail_attachment("thumbnail.png");
+
+    let create_embed = builder.to_create_embed();
+
+    assert_eq!(create_embed.0["image"], serde_json::json!({"url": "attachment://image.png"}));
+    assert_eq!(
+        create_embed.0["thumbnail"],
+        serde_json::json!({"url": "attachment://thumbnail.png"})
+    );
+
+    let mut builder = EmbedBuilder::new();
+    builder.set_attachment("image.png");
+
+    let create_embed = builder.to_create_embed();
+
+    assert_eq!(create_embed.0["image"], serde_json::json!({"url": "attachment://image.png"}));
+}
+
+#[test]
+fn test_embed_clearers() {
+    let mut builder = EmbedBuilder::new();
+    builder
+        .set_author(EmbedAuthorBuilder::new("Arius"))
+        .set_footer(EmbedFooterBuilder::new("footer"))
+        .set_title("title")
+        .add_field(("name", "value", false));
+
+    builder.clear_author().clear_footer().clear_fields();
+
+    assert!(builder.author.is_none());
+    assert!(builder.footer.is_none());
+    assert!(builder.fields.is_empty());
+    assert_eq!(builder.title.as_deref(), Some("title"));
+
+    builder.add_field(("name", "value", false)).set_colour(0xff0000);
+    let capacity = builder.fields.capacity();
+
+    builder.reset();
+
+    assert!(builder.fields.is_empty());
+    assert_eq!(builder.fields.capacity(), capacity);
+    assert!(builder.title.is_none());
+    assert!(builder.colour.is_none());
+}
+
+#[test]
+fn test_push_description() {
+    let mut builder = EmbedBuilder::new();
+    builder
+        .set_description("Scores:")
+        .push_description_line("")
+        .push_description("Arius: ")
+        .push_description(10);
+
+    assert_eq!(builder.description.as_deref(), Some("Scores:\nArius: 10"));
+}
+
+#[test]
+fn test_field_builders() {
+    let mut builder = EmbedBuilder::new();
+    builder.add_field(("tuple", "value", false)).add_field_builders(vec![
+        EmbedFieldBuilder::new("first", "value", true),
+        EmbedFieldBuilder::new("second", "value", false),
+    ]);
+
+    assert_eq!(builder.fields.len(), 3);
+    assert_eq!(builder.fields[2].name, "second");
+
+    builder.set_field_builders([EmbedFieldBuilder::new("only", "value", false)]);
+
+    assert_eq!(builder.fields.len(), 1);
+    assert_eq!(builder.fields[0].name, "only");
+}
+
+#[test]
+fn test_validate_counts_utf16() {
+    let mut builder = EmbedBuilder::new();
+
+    // 3000 characters, but 6000 UTF-16 code units.
+    builder.set_description("😀".repeat(3000));
+
+    assert_eq!(builder.total_len(), 6000);
+    assert!(matches!(builder.validate(), Err(Error::Other(_))));
+
+    builder.set_description("😀".repeat(2048));
+    assert!(builder.validate().is_ok());
+}
+
+#[test]
+fn test_message_builder_from_message() {
+    let mut msg = message();
+    assert!(MessageBuilder::from_message(&msg).content.is_none());
+
+    msg.content = "content".to_string();
+    msg.tts = true;
+    msg.embeds = vec![
+        serde_json::from_value(serde_json::json!({"type": "rich", "title": "first"})).unwrap(),
+        serde_json::from_value(serde_json::json!({"type": "rich", "title": "second"})).unwrap(),
+    ];
+
+    let builder = MessageBuilder::from_message(&msg);
+
+    assert_eq!(builder.content.as_deref(), Some("content"));
+    assert_eq!(builder.embed.unwrap().title.as_deref(), Some("first"));
+    assert!(builder.tts);
+    assert!(builder.files.is_empty());
+}
@@ -0,0 +1,240 @@
+#![allow(deprecated)]
+
+mod common;
+
+use common::{message, stub_context};
+use serenity::model::prelude::{
+    ChannelId,
+    ChannelType,
+    Emoji,
+    EmojiId,
+    GuildChannel,
+    GuildId,
+    Role,
+    User,
+    UserId,
+};
+use serenity_utils::conversion::{convert, ChannelConversion, Conversion, ConversionOptions};
+
+fn channels() -> String {
+    let channel = |id: u64, kind: u8| {
+        serde_json::json!({
+            "id": id.to_string(),
+            "type": kind,
+            "guild_id": "1",
+            "name": "general",
+            "position": id,
+            "permission_overwrites": [],
+        })
+    };
+
+    serde_json::json!([channel(10, 0), channel(11, 2), channel(12, 4)]).to_string()
+}
+
+#[tokio::test]
+async fn test_channel_conversion_filters_kind() {
+    let (ctx, _) = stub_context("200 OK", &channels());
+    let guild_id = GuildId(1);
+
+    let text = GuildChannel::text_from_guild_id_and_str(&ctx, guild_id, "general").await;
+    assert_eq!(text.map(|c| c.kind), Some(ChannelType::Text));
+
+    let voice = GuildChannel::voice_from_guild_id_and_str(&ctx, guild_id, "general").await;
+    assert_eq!(voice.map(|c| c.kind), Some(ChannelType::Voice));
+
+    let category = GuildChannel::category_from_guild_id_and_str(&ctx, guild_id, "general").await;
+    assert_eq!(category.map(|c| c.kind), Some(ChannelType::Category));
+
+    let stage = ChannelType::Stage;
+    assert!(GuildChannel::from_guild_id_and_str_of_kind(&ctx, guild_id, "general", stage)
+        .await
+        .is_none());
+
+    // A channel of a different type isn't returned, even when it's given by ID.
+    assert!(GuildChannel::voice_from_guild_id_and_str(&ctx, guild_id, "10").await.is_none());
+    assert!(GuildChannel::text_from_guild_id_and_str(&ctx, guild_id, "<#12>").await.is_none());
+}
+
+#[tokio::test]
+async fn test_here_keyword() {
+    let (ctx, _) = stub_context("200 OK", &channels());
+
+    let mut msg = message();
+    msg.channel_id = ChannelId(11);
+
+    // The message isn't from a guild.
+    assert!(GuildChannel::from_message_and_str(&ctx, &msg, "here").await.is_none());
+
+    msg.guild_id = Some(GuildId(1));
+    let channel = GuildChannel::from_message_and_str(&ctx, &msg, "HERE").await;
+    assert_eq!(channel.map(|c| c.id), Some(ChannelId(11)));
+}
+
+#[tokio::test]
+async fn test_conversion_retries_server_errors() {
+    let (ctx, requests) = stub_context("500 Internal Server Error", "{}");
+
+    let role = Role::try_from_guild_id_and_str(&ctx, GuildId(1), "admin").await;
+
+    assert!(role.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    assert!(Role::from_guild_id_and_str(&ctx, GuildId(1), "admin").await.is_none());
+}
+
+#[tokio::test]
+async fn test_conversion_not_found_is_not_an_error() {
+    let (ctx, requests) =
+        stub_context("404 Not Found", r#"{"code": 10004, "message": "Unknown Guild"}"#);
+
+    let role = Role::try_from_guild_id_and_str(&ctx, GuildId(1), "admin").await;
+
+    assert!(matches!(role, Ok(None)));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_thread_conversion() {
+    let thread = |id: u64, name: &str| {
+        serde_json::json!({
+            "id": id.to_string(),
+            "type": 11,
+            "guild_id": "1",
+            "parent_id": "10",
+            "name": name,
+            "position": 0,
+            "permission_overwrites": [],
+        })
+    };
+    let body =
+        serde_json::json!({"threads": [thread(20, "help"), thread(21, "bugs")], "members": []});
+    let (ctx, _) = stub_context("200 OK", &body.to_string());
+
+    let by_name = GuildChannel::thread_from_guild_id_and_str(&ctx, GuildId(1), "bugs").await;
+    assert_eq!(by_name.map(|t| t.id), Some(ChannelId(21)));
+
+    let by_mention = GuildChannel::thread_from_guild_id_and_str(&ctx, GuildId(1), "<#20>").await;
+    assert_eq!(by_mention.map(|t| t.kind), Some(ChannelType::PublicThread));
+
+    assert!(GuildChannel::thread_from_guild_id_and_str(&ctx, GuildId(1), "general")
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_user_conversion_outside_guild() {
+    let body = serde_json::json!({
+        "id": "5",
+        "username": "outsider",
+        "discriminator": "0005",
+        "avatar": null,
+    });
+    let (ctx, requests) = stub_context("200 OK", &body.to_string());
+
+    let user = User::from_guild_id_and_str(&ctx, GuildId(1), "<@5>").await;
+    assert_eq!(user.map(|u| u.id), Some(UserId(5)));
+
+    // Names can't be resolved without cached members.
+    assert!(User::from_guild_id_and_str(&ctx, GuildId(1), "outsider").await.is_none());
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_case_insensitive_conversion() {
+    let (ctx, _) = stub_context("200 OK", &channels());
+    let guild_id = GuildId(1);
+
+    assert!(GuildChannel::from_guild_id_and_str(&ctx, guild_id, "General").await.is_none());
+    assert!(GuildChannel::from_guild_id_and_str(&ctx, guild_id, "#general").await.is_none());
+
+    let mut options = ConversionOptions::new();
+    options.case_insensitive(true);
+
+    let channel =
+        GuildChannel::from_guild_id_and_str_with(&ctx, guild_id, "#General", options).await;
+    assert_eq!(channel.map(|c| c.name), Some("general".to_string()));
+}
+
+#[tokio::test]
+async fn test_all_matching_candidates() {
+    let (ctx, _) = stub_context("200 OK", &channels());
+    let guild_id = GuildId(1);
+
+    let all = GuildChannel::all_from_guild_id_and_str(&ctx, guild_id, "general").await;
+    let ids = all.iter().map(|c| c.id).collect::<Vec<_>>();
+    assert_eq!(ids, [ChannelId(10), ChannelId(11), ChannelId(12)]);
+
+    let by_mention = GuildChannel::all_from_guild_id_and_str(&ctx, guild_id, "<#11>").await;
+    assert_eq!(by_mention.len(), 1);
+
+    assert!(GuildChannel::all_from_guild_id_and_str(&ctx, guild_id, "random").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_fuzzy_conversion_ranks_matches() {
+    let channel = |id: u64, name: &str| {
+        serde_json::json!({
+            "id": id.to_string(),
+            "type": 0,
+            "guild_id": "1",
+            "name": name,
+            "position": id,
+            "permission_overwrites": [],
+        })
+    };
+    let body = serde_json::json!([
+        channel(13, "General"),
+        channel(12, "off-topic-general"),
+        channel(11, "general-chat"),
+        channel(10, "general"),
+    ]);
+    let (ctx, _) = stub_context("200 OK", &body.to_string());
+    let guild_id = GuildId(1);
+
+    let fuzzy = |arg: &'static str| {
+        let ctx = ctx.clone();
+        async move {
+            GuildChannel::from_guild_id_and_str_fuzzy(&ctx, guild_id, arg).await.map(|c| c.id)
+        }
+    };
+
+    assert_eq!(fuzzy("General").await, Some(ChannelId(13)));
+    assert_eq!(fuzzy("GENERAL").await, Some(ChannelId(10)));
+    assert_eq!(fuzzy("gen").await, Some(ChannelId(10)));
+    assert_eq!(fuzzy("general-").await, Some(ChannelId(11)));
+    assert_eq!(fuzzy("topic").await, Some(ChannelId(12)));
+    assert_eq!(fuzzy("voice").await, None);
+}
+
+#[tokio::test]
+async fn test_emoji_conversion() {
+    let emoji = |id: u64, name: &str| {
+        serde_json::json!({
+            "id": id.to_string(),
+            "name": name,
+            "animated": false,
+            "available": true,
+            "managed": false,
+            "require_colons": true,
+            "roles": [],
+        })
+    };
+    let body = serde_json::json!([emoji(20, "blob"), emoji(21, "blobwave")]);
+    let (ctx, _) = stub_context("200 OK", &body.to_string());
+    let guild_id = GuildId(1);
+
+    let by_name = Emoji::from_guild_id_and_str(&ctx, guild_id, ":blobwave:").await;
+    assert_eq!(by_name.map(|e| e.id), Some(EmojiId(21)));
+
+    let by_mention = Emoji::from_guild_id_and_str(&ctx, guild_id, "<a:blob:20>").await;
+    assert_eq!(by_mention.map(|e| e.name), Some("blob".to_string()));
+
+    assert!(Emoji::from_guild_id_and_str(&ctx, guild_id, "<:other:30>").await.is_none());
+}
+
+#[tokio::test]
+async fn test_convert_outside_guild() {
+    let (ctx, requests) = stub_context("200 OK", &channels());
+
+    assert!(convert::<GuildChannel>(&ctx, &message(), "general").await.is_none());
+    assert!(requests.lock().unwrap().is_empty());
+}
@@ -1,4 +1,31 @@
-use serenity_utils::formatting::{escape_mass_mentions, pagify, PagifyOptions};
+#![allow(deprecated)]
+
+use std::borrow::Cow;
+
+use serenity::model::channel::AttachmentType;
+use serenity::model::id::{ChannelId, CommandId, RoleId, UserId};
+use serenity_utils::formatting::{
+    block_quote,
+    escape_mass_mentions,
+    humanize_number,
+    mention_channel,
+    mention_role,
+    mention_slash_command,
+    mention_user,
+    message_with_text_file,
+    ordered_list,
+    pagify,
+    pagify_borrowed,
+    pluralize,
+    quote,
+    strip_markdown,
+    table_with_options,
+    text_to_files,
+    unordered_list,
+    unordered_list_with_bullet,
+    PagifyOptions,
+    TableOptions,
+};
 
 #[test]
 fn test_pagify() {
@@ -35,3 +62,196 @@ fn test_escape_mass_mentions() {
         )
     )
 }
+
+#[test]
+fn test_pagify_multibyte() {
+    let mut options = PagifyOptions::default();
+    options.shorten_by(0);
+
+    let text = "é".repeat(1500);
+    let pages = pagify(&text, options);
+
+    assert_eq!(pages.len(), 2);
+    assert!(pages.iter().all(|p| p.len() <= 2000));
+    assert_eq!(pages.concat(), text);
+}
+
+#[test]
+fn test_pagify_never_split_words() {
+    let token = "a".repeat(3000);
+    let text = format!("intro {} outro", token);
+
+    let mut options = PagifyOptions::default();
+    options.shorten_by(0).page_length(1000).never_split_words(true).max_page_length(4000);
+    let pages = pagify(&text, options);
+
+    assert_eq!(pages, vec!["intro".to_string(), format!(" {}", token), " outro".to_string()]);
+
+    // The token is longer than the hard cap, so it has to be broken.
+    let mut options = PagifyOptions::default();
+    options.shorten_by(0).page_length(1000).never_split_words(true);
+    let pages = pagify(&text, options);
+
+    assert!(pages.iter().all(|p| p.len() <= 2000));
+    assert_eq!(pages.concat(), text);
+}
+
+#[test]
+fn test_quote() {
+    assert_eq!(quote("Hello!"), "> Hello!");
+    assert_eq!(quote("Hello!\nHow are you?"), "> Hello!\n> How are you?");
+}
+
+#[test]
+fn test_block_quote() {
+    assert_eq!(block_quote("Hello!"), ">>> Hello!");
+    assert_eq!(block_quote("Hello!\nHow are you?"), ">>> Hello!\nHow are you?");
+}
+
+#[test]
+fn test_message_with_text_file() {
+    let message = message_with_text_file(Some("Export".to_string()), "data", Some("a.txt"), true);
+
+    assert_eq!(message.0["content"], "Export");
+    assert_eq!(message.2.len(), 1);
+
+    match &message.2[0] {
+        AttachmentType::Bytes {
+            data,
+            filename,
+        } => {
+            assert_eq!(&data[..], b"data");
+            assert_eq!(filename, "spoiler_a.txt");
+        },
+        _ => panic!("expected a file created from bytes"),
+    }
+}
+
+#[test]
+fn test_pagify_borrowed() {
+    let mut options = PagifyOptions::default();
+    options.page_length(12).shorten_by(0);
+
+    let pages = pagify_borrowed("Hello world!\nHi @everyone!", options);
+
+    assert_eq!(pages, vec!["Hello", " world!\nHi", " @\u{200b}everyone!"]);
+    assert!(matches!(pages[0], Cow::Borrowed(_)));
+    assert!(matches!(pages[2], Cow::Owned(_)));
+}
+
+#[test]
+fn test_mentions() {
+    assert_eq!(mention_user(UserId(1)), "<@1>");
+    assert_eq!(mention_role(RoleId(2)), "<@&2>");
+    assert_eq!(mention_channel(ChannelId(3)), "<#3>");
+    assert_eq!(mention_slash_command("settings prefix", CommandId(4)), "</settings prefix:4>");
+}
+
+#[test]
+fn test_lists() {
+    assert_eq!(ordered_list(["a", "b", "c"]), "1. a\n2. b\n3. c");
+    assert_eq!(unordered_list(vec![1, 2]), "• 1\n• 2");
+    assert_eq!(unordered_list_with_bullet(["a", "b"], "-"), "- a\n- b");
+
+    assert_eq!(ordered_list(Vec::<String>::new()), "");
+    assert_eq!(unordered_list(Vec::<String>::new()), "");
+}
+
+#[test]
+fn test_pagify_options_default() {
+    let options = PagifyOptions::default();
+
+    assert_eq!(options.shorten_by, 0);
+    assert_eq!(options.page_length, 2000);
+    assert_eq!(options.max_page_length, 2000);
+    assert!(options.escape_mass_mentions);
+}
+
+#[test]
+fn test_table_with_options() {
+    let rows = vec![vec!["alice".to_string(), "120".to_string(), "first".to_string()], vec![
+        "bob".to_string()
+    ]];
+
+    let mut options = TableOptions::new();
+    options.code_block(false);
+
+    assert_eq!(
+        table_with_options(&["Name", "Score"], &rows, options),
+        "Name  | Score |\n------+-------+------\nalice | 120   | first\nbob   |       |"
+    );
+
+    options.max_width(16);
+
+    assert_eq!(
+        table_with_options(&["Name", "Score"], &rows, options),
+        "Name | Sc… |\n-----+-----+----\nali… | 120 | fi…\nbob  |     |"
+    );
+}
+
+#[test]
+fn test_strip_markdown() {
+    assert_eq!(
+        strip_markdown("**bold** *italic* __under__ ~~struck~~ ||spoiler||"),
+        "bold italic under struck spoiler"
+    );
+    assert_eq!(strip_markdown("> quoted\n>>> block\nnot > quoted"), "quoted\nblock\nnot > quoted");
+    assert_eq!(strip_markdown("\\*not italic\\* and \\\\"), "*not italic* and \\");
+    assert_eq!(strip_markdown("snake_case ~5 a|b"), "snake_case ~5 a|b");
+
+    // Markdown inside code isn't formatted by Discord, so it's kept.
+    assert_eq!(strip_markdown("`**not bold**`"), "**not bold**");
+    assert_eq!(strip_markdown("**`code`**"), "code");
+    assert_eq!(strip_markdown("```rust\nlet _x = 1;\n```"), "let _x = 1;");
+
+    // Unterminated markers.
+    assert_eq!(strip_markdown("**bold"), "bold");
+    assert_eq!(strip_markdown("`code *here*"), "code here");
+    assert_eq!(strip_markdown("```\n~~a"), "\na");
+    assert_eq!(strip_markdown("trailing\\"), "trailing\\");
+}
+
+#[test]
+fn test_pluralize() {
+    assert_eq!(pluralize(0, "warning", None), "0 warnings");
+    assert_eq!(pluralize(1, "warning", None), "1 warning");
+    assert_eq!(pluralize(-1, "point", None), "-1 point");
+    assert_eq!(pluralize(3, "mouse", Some("mice")), "3 mice");
+}
+
+#[test]
+fn test_humanize_number() {
+    assert_eq!(humanize_number(0), "0");
+    assert_eq!(humanize_number(100_000), "100,000");
+    assert_eq!(humanize_number(i64::MIN), "-9,223,372,036,854,775,808");
+}
+
+#[test]
+fn test_text_to_files() {
+    // 30,000 lines of 100 bytes each.
+    let line = format!("{}\n", "a".repeat(99));
+    let text = line.repeat(30_000);
+
+    let files = text_to_files(&text, "log", 1_000_000);
+    assert_eq!(files.len(), 3);
+
+    for (i, file) in files.iter().enumerate() {
+        match file {
+            AttachmentType::Bytes {
+                data,
+                filename,
+            } => {
+                assert_eq!(filename, &format!("log.{}.txt", i + 1));
+                assert_eq!(data.len(), 1_000_000);
+                assert!(data.ends_with(b"\n"));
+            },
+            _ => panic!("expected a file from bytes"),
+        }
+    }
+
+    // A line that's too long is split between characters.
+    let files = text_to_files("ééé", "long", 3);
+    assert_eq!(files.len(), 3);
+
+    assert!(text_to_files("", "empty", 10).is_empty());
+}
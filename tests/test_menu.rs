@@ -0,0 +1,613 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{message, reaction, stub_context, stub_context_with};
+use serenity::async_trait;
+use serenity::builder::{CreateEmbed, CreateMessage};
+use serenity::model::prelude::{EmojiId, Reaction, ReactionType};
+use serenity_utils::formatting::PagifyOptions;
+use serenity_utils::menu::{
+    close_menu,
+    first_page,
+    jump_to_page,
+    last_page,
+    next_page,
+    paginated_message,
+    prev_page,
+    ButtonControl,
+    ButtonMenu,
+    ButtonMenuOptions,
+    CleanupBehavior,
+    Control,
+    EndCallback,
+    Menu,
+    MenuEndReason,
+    MenuOptions,
+    PageProvider,
+    TimeoutCallback,
+};
+use serenity_utils::Error;
+
+#[test]
+fn test_control_matches_animated_custom_emoji() {
+    let control = Control::new(
+        ReactionType::Custom {
+            animated: true,
+            id: EmojiId(7),
+            name: Some("blob".to_string()),
+        },
+        Arc::new(|m, r| Box::pin(close_menu(m, r))),
+    );
+
+    let reacted = ReactionType::Custom {
+        animated: false,
+        id: EmojiId(7),
+        name: None,
+    };
+    assert!(control.matches(&reacted));
+
+    let other = ReactionType::Custom {
+        animated: true,
+        id: EmojiId(8),
+        name: Some("blob".to_string()),
+    };
+    assert!(!control.matches(&other));
+}
+
+#[test]
+fn test_control_matches_unicode_emoji() {
+    let control = Control::new('▶'.into(), Arc::new(|m, r| Box::pin(close_menu(m, r))));
+
+    assert!(control.matches(&ReactionType::from('▶')));
+    assert!(!control.matches(&ReactionType::from('◀')));
+}
+
+#[tokio::test]
+async fn test_menu_ends_when_message_is_deleted() {
+    let (ctx, _) =
+        stub_context("404 Not Found", r#"{"code": 10008, "message": "Unknown Message"}"#);
+    let msg = message();
+
+    let mut page = CreateMessage::default();
+    page.content("Page number one!");
+    let pages = [page];
+
+    let options = MenuOptions {
+        message: Some(msg.clone()),
+        ..Default::default()
+    };
+
+    let (menu_msg, reason) =
+        Menu::new(&ctx, &msg, &pages, options).run_with_reason().await.unwrap();
+
+    assert!(menu_msg.is_none());
+    assert_eq!(reason, MenuEndReason::MessageDeleted);
+}
+
+#[tokio::test]
+async fn test_on_end_callback() {
+    let (ctx, _) =
+        stub_context("404 Not Found", r#"{"code": 10008, "message": "Unknown Message"}"#);
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    let ended = Arc::new(Mutex::new(None));
+    let ended_clone = Arc::clone(&ended);
+    let on_end: EndCallback = Arc::new(move |reason, message| {
+        *ended_clone.lock().unwrap() = Some((reason, message.is_some()));
+
+        Box::pin(async {})
+    });
+
+    let options = MenuOptions {
+        message: Some(msg.clone()),
+        on_end: Some(on_end),
+        ..Default::default()
+    };
+
+    Menu::new(&ctx, &msg, &pages, options).run().await.unwrap();
+
+    assert_eq!(*ended.lock().unwrap(), Some((MenuEndReason::MessageDeleted, false)));
+}
+
+#[tokio::test]
+async fn test_page_controls_wrap() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+    let pages = [CreateMessage::default(), CreateMessage::default()];
+
+    let mut menu = Menu::new(&ctx, &msg, &pages, MenuOptions::default());
+
+    prev_page(&mut menu, reaction("◀")).await;
+    assert_eq!(menu.current_index(), 1);
+
+    next_page(&mut menu, reaction("▶")).await;
+    assert_eq!(menu.current_index(), 0);
+
+    menu.options.wrap = false;
+
+    prev_page(&mut menu, reaction("◀")).await;
+    assert_eq!(menu.current_index(), 0);
+
+    next_page(&mut menu, reaction("▶")).await;
+    next_page(&mut menu, reaction("▶")).await;
+    assert_eq!(menu.current_index(), 1);
+}
+
+#[test]
+fn test_set_page_bounds() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+    let pages = [CreateMessage::default(), CreateMessage::default()];
+
+    let mut menu = Menu::new(&ctx, &msg, &pages, MenuOptions::default());
+
+    assert!(menu.set_page(1));
+    assert_eq!(menu.current_index(), 1);
+
+    assert!(!menu.set_page(2));
+    assert_eq!(menu.current_index(), 1);
+}
+
+#[tokio::test]
+async fn test_end_controls() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+    let pages = [CreateMessage::default(), CreateMessage::default(), CreateMessage::default()];
+
+    let options = MenuOptions::default_controls_with_ends();
+    let emojis = options.controls.iter().map(|c| c.emoji.to_string()).collect::<Vec<_>>();
+    assert_eq!(emojis, ["⏪", "◀", "❌", "▶", "⏩"]);
+
+    let mut menu = Menu::new(&ctx, &msg, &pages, options);
+
+    last_page(&mut menu, reaction("⏩")).await;
+    assert_eq!(menu.current_index(), 2);
+
+    first_page(&mut menu, reaction("⏪")).await;
+    assert_eq!(menu.current_index(), 0);
+}
+
+#[tokio::test]
+async fn test_readd_reactions() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+    let pages = [CreateMessage::default(), CreateMessage::default()];
+
+    let mut menu = Menu::new(&ctx, &msg, &pages, MenuOptions::default());
+
+    next_page(&mut menu, reaction("▶")).await;
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    menu.options.readd_reactions = true;
+
+    prev_page(&mut menu, reaction("◀")).await;
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_from_fields_per_page_limit() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result =
+        Menu::from_fields(&ctx, &msg, "Fields", Vec::new(), 26, MenuOptions::default()).await;
+
+    assert!(matches!(result, Err(Error::Other(_))));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_new_from_strings() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let menu = Menu::new_from(&ctx, &msg, vec!["one", "two"], MenuOptions::default());
+
+    assert_eq!(menu.pages.len(), 2);
+    assert_eq!(menu.pages[1].0["content"], "two");
+}
+
+#[tokio::test]
+async fn test_new_from_embed_groups() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let embed = |title: &str| {
+        let mut embed = CreateEmbed::default();
+        embed.title(title);
+
+        embed
+    };
+    let pages = vec![vec![embed("one"), embed("two")], vec![embed("three")]];
+
+    let menu = Menu::new_from(&ctx, &msg, pages, MenuOptions::default());
+
+    assert_eq!(menu.pages.len(), 2);
+    assert_eq!(menu.pages[0].0["embeds"].as_array().unwrap().len(), 2);
+    assert_eq!(menu.pages[1].0["embeds"][0]["title"], "three");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_new_from_embed_builders() {
+    use serenity_utils::builder::embed::EmbedBuilder;
+
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let embeds = ["one", "two"]
+        .iter()
+        .map(|title| {
+            let mut embed = EmbedBuilder::new();
+            embed.set_title(*title);
+
+            embed
+        })
+        .collect::<Vec<_>>();
+
+    let menu = Menu::new_from(&ctx, &msg, embeds, MenuOptions::default());
+
+    assert_eq!(menu.pages.len(), 2);
+    assert_eq!(menu.pages[1].0["embeds"].as_array().unwrap().len(), 1);
+    assert_eq!(menu.pages[1].0["embeds"][0]["title"], "two");
+}
+
+#[test]
+fn test_options_setters() {
+    let mut options = MenuOptions::default();
+    options
+        .page(2)
+        .timeout(10.0)
+        .message(Some(message()))
+        .controls(Vec::new())
+        .add_control(noop_control('✅'))
+        .non_blocking(false);
+
+    assert_eq!(options.page, 2);
+    assert_eq!(options.timeout, 10.0);
+    assert!(options.message.is_some());
+    assert_eq!(options.controls.len(), 1);
+    assert!(!options.non_blocking);
+}
+
+#[test]
+fn test_set_timeout() {
+    let mut options = MenuOptions::default();
+    options.set_timeout(Duration::from_millis(1500));
+
+    assert_eq!(options.timeout, 1.5);
+}
+
+#[test]
+fn test_clone_options() {
+    let options = MenuOptions::default();
+    let cloned = options.clone();
+
+    assert_eq!(cloned.controls.len(), options.controls.len());
+    assert!(Arc::ptr_eq(&cloned.controls[0].function, &options.controls[0].function));
+}
+
+fn noop_control(emoji: char) -> Control {
+    Control::new(emoji.into(), Arc::new(|m, r| Box::pin(close_menu(m, r))))
+}
+
+#[test]
+fn test_validate_reaction_limit() {
+    let mut options = MenuOptions {
+        controls: ('a'..='t').map(noop_control).collect(),
+        ..Default::default()
+    };
+    assert!(options.validate().is_ok());
+
+    options.controls.push(noop_control('u'));
+    assert!(matches!(options.validate(), Err(Error::Other(_))));
+}
+
+#[test]
+fn test_dedupe_controls() {
+    let mut options = MenuOptions {
+        controls: vec![noop_control('◀'), noop_control('❌'), noop_control('◀')],
+        ..Default::default()
+    };
+    options.dedupe_controls();
+
+    assert_eq!(options.controls.len(), 2);
+    assert!(options.controls[0].matches(&ReactionType::from('◀')));
+    assert!(options.controls[1].matches(&ReactionType::from('❌')));
+}
+
+#[tokio::test]
+async fn test_duplicate_controls_error() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    let options = MenuOptions {
+        controls: vec![noop_control('◀'), noop_control('❌'), noop_control('◀')],
+        ..Default::default()
+    };
+
+    match Menu::new(&ctx, &msg, &pages, options).run().await {
+        Err(Error::Other(e)) => assert_eq!(e, "duplicate control emoji: ◀"),
+        _ => panic!("expected an error for the duplicate emoji"),
+    }
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_paginated_message_empty_text() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result =
+        paginated_message(&ctx, &msg, " \n ", None, Default::default(), MenuOptions::default())
+            .await;
+
+    assert!(matches!(result, Err(Error::Other(_))));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+struct CountingProvider(Arc<AtomicUsize>);
+
+#[async_trait]
+impl<'a> PageProvider<'a> for CountingProvider {
+    async fn page(&self, index: usize) -> CreateMessage<'a> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+
+        let mut page = CreateMessage::default();
+        page.content(format!("Page number {}!", index + 1));
+
+        page
+    }
+
+    fn len(&self) -> usize {
+        3
+    }
+}
+
+#[tokio::test]
+async fn test_new_with_provider() {
+    let (ctx, _) =
+        stub_context("404 Not Found", r#"{"code": 10008, "message": "Unknown Message"}"#);
+    let msg = message();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let options = MenuOptions {
+        message: Some(msg.clone()),
+        page: 2,
+        ..Default::default()
+    };
+
+    let mut menu = Menu::new_with_provider(&ctx, &msg, CountingProvider(calls.clone()), options);
+    assert_eq!(menu.page_count(), 3);
+    assert!(!menu.set_page(3));
+
+    let (_, reason) = menu.run_with_reason().await.unwrap();
+
+    assert_eq!(reason, MenuEndReason::MessageDeleted);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_button_menu_duplicate_custom_ids() {
+    let mut options = ButtonMenuOptions::default();
+    assert!(options.validate().is_ok());
+
+    options.controls.push(ButtonControl::next_page());
+    assert!(options.validate().is_err());
+
+    options.controls = (0..26).map(|_| ButtonControl::close()).collect();
+    assert!(options.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_button_menu_ends_when_message_is_deleted() {
+    let (ctx, _) =
+        stub_context("404 Not Found", r#"{"code": 10008, "message": "Unknown Message"}"#);
+    let msg = message();
+
+    let options = ButtonMenuOptions {
+        message: Some(msg.clone()),
+        ..Default::default()
+    };
+
+    let (menu_msg, reason) = ButtonMenu::new_from(&ctx, &msg, vec!["Page number one!"], options)
+        .run_with_reason()
+        .await
+        .unwrap();
+
+    assert!(menu_msg.is_none());
+    assert_eq!(reason, MenuEndReason::MessageDeleted);
+}
+
+#[tokio::test]
+async fn test_jump_to_page_without_answer() {
+    let body = serde_json::to_string(&message()).unwrap();
+    let (ctx, requests) = stub_context_with(move |i| match i {
+        // The question sent to the user.
+        1 => ("200 OK".into(), body.clone()),
+        _ => ("204 No Content".into(), String::new()),
+    });
+    let msg = message();
+    let pages = [CreateMessage::default(), CreateMessage::default()];
+
+    let options = MenuOptions {
+        timeout: 0.1,
+        ..Default::default()
+    };
+    let mut menu = Menu::new(&ctx, &msg, &pages, options);
+
+    jump_to_page(&mut menu, reaction("🔢")).await;
+
+    assert_eq!(menu.current_index(), 0);
+    // The reaction is removed, then the question is sent and deleted.
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_cleanup_behavior() {
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    for (cleanup, request_count) in [
+        (CleanupBehavior::DeleteMessage, 2),
+        (CleanupBehavior::DeleteAllReactions, 2),
+        (CleanupBehavior::DeleteOwnReactions, 4),
+        (CleanupBehavior::Nothing, 1),
+    ] {
+        let body = serde_json::to_string(&msg).unwrap();
+        let (ctx, requests) = stub_context_with(move |i| match i {
+            // The edit of the menu's message.
+            0 => ("200 OK".into(), body.clone()),
+            _ => ("204 No Content".into(), String::new()),
+        });
+
+        let options = MenuOptions {
+            message: Some(msg.clone()),
+            timeout: 0.1,
+            cleanup,
+            ..Default::default()
+        };
+
+        let (menu_msg, reason) =
+            Menu::new(&ctx, &msg, &pages, options).run_with_reason().await.unwrap();
+
+        assert_eq!(reason, MenuEndReason::TimedOut);
+        assert_eq!(menu_msg.is_none(), cleanup == CleanupBehavior::DeleteMessage);
+        assert_eq!(requests.lock().unwrap().len(), request_count, "{:?}", cleanup);
+    }
+}
+
+#[tokio::test]
+async fn test_reaction_delay_forces_blocking() {
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    let body = serde_json::to_string(&msg).unwrap();
+    let (ctx, requests) = stub_context_with(move |i| match i {
+        // The menu's message being sent.
+        0 => ("200 OK".into(), body.clone()),
+        _ => ("204 No Content".into(), String::new()),
+    });
+
+    let delay = Duration::from_millis(100);
+    let options = MenuOptions {
+        timeout: 0.01,
+        non_blocking: true,
+        reaction_delay: Some(delay),
+        cleanup: CleanupBehavior::Nothing,
+        ..Default::default()
+    };
+
+    Menu::new(&ctx, &msg, &pages, options).run().await.unwrap();
+
+    // All three controls are added before the menu times out.
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+
+    for pair in requests[1..].windows(2) {
+        assert!(pair[1] - pair[0] >= delay);
+    }
+}
+
+async fn select_second(menu: &mut Menu<'_>, _reaction: Reaction) {
+    menu.set_result(1usize);
+}
+
+#[tokio::test]
+async fn test_consider_existing_reactions() {
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    let user = |id: u64| {
+        serde_json::json!({
+            "id": id.to_string(),
+            "username": "user",
+            "discriminator": "0001",
+            "avatar": null,
+        })
+    };
+    let body = serde_json::to_string(&msg).unwrap();
+    let first_page = serde_json::json!((100..200).map(user).collect::<Vec<_>>()).to_string();
+    let second_page = serde_json::json!([user(3)]).to_string();
+    let (ctx, requests) = stub_context_with(move |i| match i {
+        // The edit of the menu's message.
+        0 => ("200 OK".into(), body.clone()),
+        // The first control's emoji can't be queried.
+        1 => ("400 Bad Request".into(), r#"{"code": 10014, "message": "Unknown Emoji"}"#.into()),
+        // The user is on the second page of the second control's users.
+        2 => ("200 OK".into(), first_page.clone()),
+        _ => ("200 OK".into(), second_page.clone()),
+    });
+
+    let options = MenuOptions {
+        message: Some(msg.clone()),
+        controls: vec![
+            noop_control('❌'),
+            Control::new('✅'.into(), Arc::new(|m, r| Box::pin(select_second(m, r)))),
+        ],
+        consider_existing_reactions: true,
+        ..Default::default()
+    };
+
+    let selected = Menu::new(&ctx, &msg, &pages, options).run_until::<usize>().await.unwrap();
+
+    assert_eq!(selected, Some(1));
+    assert_eq!(requests.lock().unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_on_timeout_callback() {
+    let msg = message();
+    let pages = [CreateMessage::default()];
+
+    let body = serde_json::to_string(&msg).unwrap();
+    let (ctx, _) = stub_context_with(move |_| ("200 OK".into(), body.clone()));
+
+    let timed_out = Arc::new(AtomicUsize::new(0));
+    let timed_out_clone = Arc::clone(&timed_out);
+    let on_timeout: TimeoutCallback = Arc::new(move |menu| {
+        assert!(menu.message().is_some());
+        timed_out_clone.fetch_add(1, Ordering::SeqCst);
+
+        Box::pin(async {})
+    });
+
+    let options = MenuOptions {
+        message: Some(msg.clone()),
+        timeout: 0.1,
+        cleanup: CleanupBehavior::Nothing,
+        on_timeout: Some(on_timeout),
+        ..Default::default()
+    };
+
+    let (_, reason) = Menu::new(&ctx, &msg, &pages, options).run_with_reason().await.unwrap();
+
+    assert_eq!(reason, MenuEndReason::TimedOut);
+    assert_eq!(timed_out.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_from_text_code_block() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+    let text = "first line\nsecond line\nthird line";
+
+    let mut pagify_options = PagifyOptions::new();
+    pagify_options.page_length(30);
+
+    let menu =
+        Menu::from_text(&ctx, &msg, text, Some("rs"), pagify_options, MenuOptions::default());
+
+    assert!(menu.pages.len() > 1);
+    for page in menu.pages.iter() {
+        let content = page.0["content"].as_str().unwrap();
+
+        assert!(content.starts_with("```rs\n") && content.ends_with("\n```"));
+        assert!(content.chars().count() <= 30);
+    }
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::{message, stub_context, stub_context_with};
+use serenity::model::prelude::{ChannelId, MessageId, ReactionType};
+use serenity::utils::Colour;
+use serenity_utils::misc::{
+    add_reactions_blocking,
+    add_reactions_blocking_with_delay,
+    delete_messages,
+    parse_colour,
+    remove_own_reactions,
+    try_add_reactions_blocking,
+};
+use serenity_utils::Error;
+
+#[tokio::test]
+async fn test_add_reactions_blocking_with_delay() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let emojis: Vec<ReactionType> = vec!['◀'.into(), '❌'.into(), '▶'.into()];
+    let delay = Duration::from_millis(100);
+
+    add_reactions_blocking_with_delay(&ctx, &message(), &emojis, delay).await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+
+    for pair in requests.windows(2) {
+        assert!(pair[1] - pair[0] >= delay);
+    }
+}
+
+#[tokio::test]
+async fn test_add_reactions_blocking_continues_after_failure() {
+    let (ctx, requests) = stub_context_with(|i| match i {
+        1 => ("400 Bad Request".into(), r#"{"code": 10014, "message": "Unknown Emoji"}"#.into()),
+        _ => ("204 No Content".into(), String::new()),
+    });
+    let emojis: Vec<ReactionType> = vec!['◀'.into(), '❌'.into(), '▶'.into()];
+
+    let result = try_add_reactions_blocking(&ctx, &message(), &emojis).await;
+
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    match result {
+        Err(Error::FailedReactions(failures)) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, emojis[1]);
+        },
+        other => panic!("expected failed reactions, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_add_reactions_blocking_returns_first_failure() {
+    let (ctx, requests) = stub_context_with(|i| match i {
+        1 => ("400 Bad Request".into(), r#"{"code": 10014, "message": "Unknown Emoji"}"#.into()),
+        _ => ("204 No Content".into(), String::new()),
+    });
+    let emojis: Vec<ReactionType> = vec!['◀'.into(), '❌'.into(), '▶'.into()];
+
+    assert!(add_reactions_blocking(&ctx, &message(), &emojis).await.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[test]
+fn test_parse_colour() {
+    let red = Some(Colour::new(0xff0000));
+
+    assert_eq!(parse_colour("red"), red);
+    assert_eq!(parse_colour(" Red "), red);
+    assert_eq!(parse_colour("#ff0000"), red);
+    assert_eq!(parse_colour("#F00"), red);
+    assert_eq!(parse_colour("0xff0000"), red);
+    assert_eq!(parse_colour("ff0000"), red);
+    assert_eq!(parse_colour("rgb(255, 0, 0)"), red);
+    assert_eq!(parse_colour("not quite black"), Some(Colour::new(0x23272a)));
+
+    assert_eq!(parse_colour("reddish"), None);
+    assert_eq!(parse_colour("#ff00"), None);
+    assert_eq!(parse_colour("+fffff"), None);
+    assert_eq!(parse_colour("rgb(256, 0, 0)"), None);
+    assert_eq!(parse_colour("rgb(255, 0)"), None);
+}
+
+#[tokio::test]
+async fn test_remove_own_reactions() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let emojis: Vec<ReactionType> = vec!['✅'.into(), '❌'.into()];
+
+    remove_own_reactions(&ctx, &message(), &emojis).await.unwrap();
+
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_delete_messages() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+
+    // Snowflakes store the milliseconds since Discord's epoch, the first
+    // second of 2015.
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+    let recent = (now - 1_420_070_400_000) << 22;
+
+    let mut messages = (0..150).map(|i| MessageId(recent + i)).collect::<Vec<_>>();
+    messages.extend([MessageId(1 << 22), MessageId(2 << 22), MessageId(2 << 22)]);
+
+    let deleted = delete_messages(&ctx, ChannelId(1), &messages).await.unwrap();
+
+    assert_eq!(deleted, 152);
+    // Two bulk deletes and two single deletes.
+    assert_eq!(requests.lock().unwrap().len(), 4);
+}
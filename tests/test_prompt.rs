@@ -0,0 +1,254 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{message, stub_context, stub_context_with};
+use serenity::futures::future;
+use serenity::model::prelude::ReactionType;
+use serenity_utils::prompt::{
+    cancellable,
+    dm_ask,
+    dm_message_prompt,
+    message_prompt,
+    message_prompt_content_cleanup,
+    message_prompt_validated,
+    number_prompt,
+    reaction_or_message_prompt,
+    reaction_prompt,
+    reaction_prompt_map,
+    reaction_prompt_no_add,
+    select_prompt,
+    yes_or_no_prompt_with,
+};
+use serenity_utils::Error;
+
+#[tokio::test]
+async fn test_cancellable() {
+    let result = cancellable(future::ready(7), future::pending::<()>()).await;
+    assert!(matches!(result, Ok(7)));
+
+    let result = cancellable(future::pending::<Option<String>>(), future::ready(())).await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[tokio::test]
+async fn test_zero_timeout_waits() {
+    let (ctx, _) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let prompt = message_prompt(&ctx, &msg, &msg.author, 0.0);
+    let result = cancellable(prompt, tokio::time::sleep(Duration::from_millis(100))).await;
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[tokio::test]
+async fn test_select_prompt_option_limit() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let options = (0..26).map(|i| (i.to_string(), i.to_string())).collect::<Vec<_>>();
+    let result = select_prompt(&ctx, msg.channel_id, &msg.author, "Pick one", &options, 1.0).await;
+
+    assert!(matches!(result, Err(Error::Other(_))));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_dm_ask_dms_disabled() {
+    let body = r#"{"code": 50007, "message": "Cannot send messages to this user"}"#;
+    let (ctx, _) = stub_context("403 Forbidden", body);
+    let msg = message();
+
+    let result = dm_ask(&ctx, &msg.author, "Hello?", 1.0).await;
+
+    assert!(matches!(result, Err(Error::DmsDisabled)));
+}
+
+#[tokio::test]
+async fn test_reaction_or_message_prompt_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+    let emojis = [ReactionType::from('👍')];
+
+    let result = reaction_or_message_prompt(&ctx, &msg, &msg.author, &emojis, 0.1).await;
+
+    assert!(matches!(result, Err(Error::TimeoutError)));
+    // The channel is fetched to check permissions, then the reaction is added
+    // and removed.
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_reaction_prompt_missing_permissions() {
+    let current_user = serde_json::json!({
+        "id": "6",
+        "username": "bot",
+        "discriminator": "0001",
+        "avatar": null,
+        "mfa_enabled": false,
+    });
+    let channel = serde_json::json!({
+        "id": "1",
+        "type": 0,
+        "guild_id": "4",
+        "name": "general",
+        "position": 0,
+        "permission_overwrites": [],
+    });
+    // `@everyone` can only send messages.
+    let guild = serde_json::json!({
+        "id": "4",
+        "name": "guild",
+        "owner_id": "5",
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "default_message_notifications": 0,
+        "emojis": [],
+        "features": [],
+        "icon": null,
+        "mfa_level": 0,
+        "roles": [{
+            "id": "4",
+            "name": "@everyone",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "mentionable": false,
+            "permissions": "2048",
+            "position": 0,
+        }],
+        "splash": null,
+        "discovery_splash": null,
+        "system_channel_id": null,
+        "system_channel_flags": 0,
+        "rules_channel_id": null,
+        "public_updates_channel_id": null,
+        "verification_level": 0,
+        "description": null,
+        "premium_subscription_count": 0,
+        "banner": null,
+        "vanity_url_code": null,
+        "nsfw_level": 0,
+        "stickers": [],
+    });
+    let member = serde_json::json!({
+        "user": current_user.clone(),
+        "roles": [],
+        "joined_at": "2021-01-01T00:00:00.000Z",
+        "deaf": false,
+        "mute": false,
+    });
+
+    // The requests made to find the current user's permissions, in order.
+    let mut responses = vec![channel, guild];
+    #[cfg(not(feature = "cache"))]
+    responses.push(current_user);
+    responses.push(member);
+
+    let count = responses.len();
+    let (ctx, requests) = stub_context_with(move |i| ("200 OK".into(), responses[i].to_string()));
+    let msg = message();
+    let emojis = [ReactionType::from('👍')];
+
+    let result = reaction_prompt(&ctx, &msg, &msg.author, &emojis, 0.1).await;
+
+    assert!(matches!(result, Err(Error::MissingPermissions(p)) if p.add_reactions()));
+    // No reaction is added.
+    assert_eq!(requests.lock().unwrap().len(), count);
+}
+
+#[tokio::test]
+async fn test_reaction_prompt_no_add() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+    let emojis = [ReactionType::from('👍')];
+
+    let result = reaction_prompt_no_add(&ctx, &msg, &msg.author, &emojis, 0.1).await;
+
+    assert!(matches!(result, Err(Error::TimeoutError)));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_message_prompt_validated_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result = message_prompt_validated(&ctx, &msg, &msg.author, 0.1, 3, |s| {
+        s.parse::<u8>().map_err(|e| e.to_string())
+    })
+    .await;
+
+    assert!(result.is_none());
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_number_prompt_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result = number_prompt(&ctx, &msg, &msg.author, 1..=10, 0.1).await;
+
+    assert!(result.is_none());
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_reaction_prompt_map_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+    let choices = [(ReactionType::from('🗡'), "attack"), (ReactionType::from('🛡'), "defend")];
+
+    let result = reaction_prompt_map(&ctx, &msg, &msg.author, &choices, 0.1).await;
+
+    assert!(matches!(result, Err(Error::TimeoutError)));
+    // The channel is fetched to check permissions, then the reactions are
+    // added and removed.
+    assert_eq!(requests.lock().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn test_message_prompt_content_cleanup_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result = message_prompt_content_cleanup(&ctx, &msg, &msg.author, 0.1, true).await;
+
+    assert!(result.is_none());
+    // Nothing is deleted without an answer.
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_dm_message_prompt_channel_error() {
+    let body = r#"{"code": 50007, "message": "Cannot send messages to this user"}"#;
+    let (ctx, _) = stub_context("403 Forbidden", body);
+    let msg = message();
+
+    let result = dm_message_prompt(&ctx, &msg.author, 1.0).await;
+
+    assert!(matches!(result, Err(Error::SerenityError(_))));
+}
+
+#[tokio::test]
+async fn test_yes_or_no_prompt_with_timeout() {
+    let (ctx, requests) = stub_context("204 No Content", "");
+    let msg = message();
+
+    let result = yes_or_no_prompt_with(
+        &ctx,
+        &msg,
+        &msg.author,
+        '👍'.into(),
+        '👎'.into(),
+        Duration::from_millis(100),
+    )
+    .await;
+
+    assert!(matches!(result, Err(Error::TimeoutError)));
+    // The channel is fetched to check permissions, then the reactions are
+    // added and removed.
+    assert_eq!(requests.lock().unwrap().len(), 5);
+}